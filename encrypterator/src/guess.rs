@@ -0,0 +1,111 @@
+use crate::TRANSFORMED_KEY_LEN;
+
+const HALF_KEY_LEN: usize = TRANSFORMED_KEY_LEN / 2;
+
+const PNG_HEADER: &[u8] = b"\x89PNG\r\n\x1A\n\0\0\0\x0DIHDR";
+
+/// Make the full transformed key from its first half.
+///
+/// The second half is the first half, reversed.
+fn expand_half_key(half_key: [u8; HALF_KEY_LEN]) -> [u8; TRANSFORMED_KEY_LEN] {
+    let mut key = [0; TRANSFORMED_KEY_LEN];
+    key[..HALF_KEY_LEN].copy_from_slice(&half_key);
+    for (key_byte, half_key_byte) in key[HALF_KEY_LEN..]
+        .iter_mut()
+        .zip(half_key.iter().rev().copied())
+    {
+        *key_byte = half_key_byte;
+    }
+    key
+}
+
+/// Guess the transformed key from the start of an encrypted png body.
+pub(crate) fn guess_png_key(body: &[u8]) -> Option<[u8; TRANSFORMED_KEY_LEN]> {
+    let png_header = body.get(..TRANSFORMED_KEY_LEN)?;
+
+    let mut half_key = [0; HALF_KEY_LEN];
+    for ((expected_png_byte, actual_png_byte), key_byte) in PNG_HEADER
+        .iter()
+        .copied()
+        .zip(png_header.iter().copied())
+        .zip(half_key.iter_mut())
+    {
+        *key_byte = actual_png_byte ^ expected_png_byte;
+    }
+
+    Some(expand_half_key(half_key))
+}
+
+/// Guess the transformed key from the start of an encrypted ogg body.
+///
+/// The first 14 bytes of the first page are fixed.
+/// Because the second half of the key mirrors the first,
+/// this gives every key byte except the 2 that cover the start of the stream serial number.
+/// These are recovered by checking candidates against the page crc,
+/// which requires the entire first page to be present in the body.
+///
+/// Returns `None` if the body does not look like an ogg.
+pub(crate) fn guess_ogg_key(body: &[u8]) -> Option<[u8; TRANSFORMED_KEY_LEN]> {
+    let mut half_key = [0; HALF_KEY_LEN];
+    for ((expected_byte, actual_byte), key_byte) in ogg_page::FIRST_PAGE_HEADER
+        .iter()
+        .copied()
+        .zip(
+            body.get(..ogg_page::FIRST_PAGE_HEADER.len())?
+                .iter()
+                .copied(),
+        )
+        .zip(half_key.iter_mut())
+    {
+        *key_byte = actual_byte ^ expected_byte;
+    }
+
+    // The serial number bytes are wrong here, but they will be fixed up later.
+    let key = expand_half_key(half_key);
+    let decrypt = |body: &[u8]| -> Vec<u8> {
+        body.iter()
+            .copied()
+            .enumerate()
+            .map(|(index, byte)| byte ^ key.get(index).copied().unwrap_or(0))
+            .collect()
+    };
+
+    let header = decrypt(body.get(..ogg_page::SEGMENT_TABLE_OFFSET)?);
+
+    // The first page always has a sequence number of 0.
+    // This can cheaply rule out other formats.
+    if header[ogg_page::PAGE_SEQUENCE_OFFSET..ogg_page::PAGE_SEQUENCE_OFFSET + 4] != [0; 4] {
+        return None;
+    }
+
+    let segment_count = usize::from(header[ogg_page::SEGMENT_COUNT_OFFSET]);
+    let segment_table_end = ogg_page::SEGMENT_TABLE_OFFSET + segment_count;
+    let header = decrypt(body.get(..segment_table_end)?);
+    let data_len: usize = header[ogg_page::SEGMENT_TABLE_OFFSET..]
+        .iter()
+        .copied()
+        .map(usize::from)
+        .sum();
+    let page = decrypt(body.get(..segment_table_end + data_len)?);
+
+    let encrypted: [u8; 4] = body[ogg_page::SERIAL_OFFSET..ogg_page::PAGE_SEQUENCE_OFFSET]
+        .try_into()
+        .expect("invalid serial len");
+    // The unknown bytes all lie within 32 bits,
+    // and the crc will catch any burst error that small,
+    // so there can only ever be 1 match.
+    let candidates = (0..=u16::MAX).map(|candidate| {
+        let [low, high] = candidate.to_le_bytes();
+        [
+            encrypted[0] ^ low,
+            encrypted[1] ^ high,
+            encrypted[2] ^ high,
+            encrypted[3] ^ low,
+        ]
+    });
+    let serial = ogg_page::find_serial(&page, candidates)?;
+
+    half_key[ogg_page::SERIAL_OFFSET] = serial[0] ^ encrypted[0];
+    half_key[ogg_page::SERIAL_OFFSET + 1] = serial[1] ^ encrypted[1];
+    Some(expand_half_key(half_key))
+}
@@ -1,12 +1,20 @@
+mod guess;
 mod reader;
+mod writer;
 
 pub use self::reader::Reader;
+pub use self::writer::Writer;
 use md5::Digest;
 use md5::Md5;
 
-const MAGIC_LEN: usize = 32;
-const MAGIC: &[u8; MAGIC_LEN] = b"ART\0ENCRYPTER100FREE\0VERSION\0\0\0\0";
-const TRANSFORMED_KEY_LEN: usize = 32;
+/// The length of the magic, in bytes.
+pub const MAGIC_LEN: usize = 32;
+
+/// The magic at the start of every encrypted file.
+pub const MAGIC: &[u8; MAGIC_LEN] = b"ART\0ENCRYPTER100FREE\0VERSION\0\0\0\0";
+
+/// The length of a transformed key, in bytes.
+pub const TRANSFORMED_KEY_LEN: usize = 32;
 
 /// Library error type
 #[derive(Debug, thiserror::Error)]
@@ -37,3 +45,147 @@ fn transform_encryption_key(input: &str) -> [u8; TRANSFORMED_KEY_LEN] {
 
     *output.as_array().expect("invalid key len")
 }
+
+/// Find the passphrase for a transformed key from a list of candidates.
+///
+/// Candidates may be strings pulled from the game's executable or scripts.
+/// The transformed key can be recovered from any encrypted png with a [`Reader`].
+pub fn find_passphrase<I>(
+    transformed_key: &[u8; TRANSFORMED_KEY_LEN],
+    candidates: I,
+) -> Option<I::Item>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    candidates
+        .into_iter()
+        .find(|candidate| transform_encryption_key(candidate.as_ref()) == *transformed_key)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Read;
+    use std::io::Write;
+
+    const KEY: &str = "test key";
+
+    /// Make a fake png, which is long enough to cover the encrypted part.
+    fn make_png() -> Vec<u8> {
+        let mut png = b"\x89PNG\r\n\x1A\n\0\0\0\x0DIHDR".to_vec();
+        png.extend((0..100).map(|i: u8| i.wrapping_mul(31)));
+        png
+    }
+
+    /// Make a fake ogg, with a valid first page.
+    fn make_ogg() -> Vec<u8> {
+        let mut ogg = b"OggS\0\x02\0\0\0\0\0\0\0\0".to_vec();
+        // Serial number, sequence number, and crc.
+        ogg.extend([0x12, 0x34, 0x56, 0x78, 0, 0, 0, 0, 0, 0, 0, 0]);
+        ogg.extend([1, 30]);
+        ogg.extend(
+            b"\x01vorbis"
+                .iter()
+                .copied()
+                .chain(std::iter::repeat_n(7, 23)),
+        );
+        let crc = ogg_page::crc(&ogg);
+        ogg[22..26].copy_from_slice(&crc.to_le_bytes());
+
+        ogg.extend(b"OggS\0\0");
+        ogg.extend(std::iter::repeat_n(3, 100));
+        ogg
+    }
+
+    #[test]
+    fn writer_reader() {
+        let png = make_png();
+
+        let mut encrypted = Vec::new();
+        let mut writer = Writer::new(&mut encrypted, KEY);
+        writer.write_all(&png).expect("failed to write");
+        writer.flush().expect("failed to flush");
+
+        assert!(encrypted.starts_with(MAGIC));
+        assert!(encrypted.len() == MAGIC_LEN + png.len());
+        assert!(encrypted[MAGIC_LEN + TRANSFORMED_KEY_LEN..] == png[TRANSFORMED_KEY_LEN..]);
+
+        let mut reader = Reader::new(encrypted.as_slice());
+        reader.set_key(KEY).expect("failed to set key");
+        let mut decrypted = Vec::new();
+        reader.read_to_end(&mut decrypted).expect("failed to read");
+        assert!(decrypted == png);
+
+        let mut reader = Reader::new(encrypted.as_slice());
+        reader.guess_key().expect("failed to guess key");
+        let mut decrypted = Vec::new();
+        reader.read_to_end(&mut decrypted).expect("failed to read");
+        assert!(decrypted == png);
+
+        let mut reencrypted = Vec::new();
+        let mut writer = Writer::new(&mut reencrypted, KEY);
+        for chunk in decrypted.chunks(7) {
+            writer.write_all(chunk).expect("failed to write");
+        }
+        assert!(reencrypted == encrypted);
+    }
+
+    #[test]
+    fn writer_short() {
+        let mut encrypted = Vec::new();
+        let mut writer = Writer::new(&mut encrypted, KEY);
+        writer.write_all(b"short").expect("failed to write");
+
+        let mut reader = Reader::new(encrypted.as_slice());
+        reader.set_key(KEY).expect("failed to set key");
+        let mut decrypted = Vec::new();
+        reader.read_to_end(&mut decrypted).expect("failed to read");
+        assert!(decrypted == b"short");
+    }
+
+    #[test]
+    fn guess_ogg_key() {
+        let ogg = make_ogg();
+
+        let mut encrypted = Vec::new();
+        let mut writer = Writer::new(&mut encrypted, KEY);
+        writer.write_all(&ogg).expect("failed to write");
+
+        let mut reader = Reader::new(encrypted.as_slice());
+        reader.guess_key().expect("failed to guess key");
+        assert!(reader.transformed_key() == Some(transform_encryption_key(KEY)));
+        let mut decrypted = Vec::new();
+        reader.read_to_end(&mut decrypted).expect("failed to read");
+        assert!(decrypted == ogg);
+    }
+
+    #[test]
+    fn reuse_key() {
+        let mut encrypted_png = Vec::new();
+        Writer::new(&mut encrypted_png, KEY)
+            .write_all(&make_png())
+            .expect("failed to write");
+
+        let mut reader = Reader::new(encrypted_png.as_slice());
+        reader.guess_key().expect("failed to guess key");
+        let key = reader.transformed_key().expect("missing key");
+
+        let ogg = make_ogg();
+        let mut encrypted_ogg = Vec::new();
+        Writer::with_transformed_key(&mut encrypted_ogg, key)
+            .write_all(&ogg)
+            .expect("failed to write");
+
+        let mut reader = Reader::new(encrypted_ogg.as_slice());
+        reader.set_transformed_key(key).expect("failed to set key");
+        let mut decrypted = Vec::new();
+        reader.read_to_end(&mut decrypted).expect("failed to read");
+        assert!(decrypted == ogg);
+        assert!(reader.transformed_key() == Some(key));
+
+        let candidates = ["foo", "bar", KEY, "baz"];
+        assert!(find_passphrase(&key, candidates) == Some(KEY));
+        assert!(find_passphrase(&key, ["foo", "bar"]).is_none());
+    }
+}
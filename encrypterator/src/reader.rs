@@ -2,10 +2,10 @@ use crate::Error;
 use crate::MAGIC;
 use crate::MAGIC_LEN;
 use crate::TRANSFORMED_KEY_LEN;
+use crate::guess::guess_ogg_key;
+use crate::guess::guess_png_key;
 use crate::transform_encryption_key;
 
-const PNG_HEADER: &[u8] = b"\x89PNG\r\n\x1A\n\0\0\0\x0DIHDR";
-
 #[derive(Debug)]
 enum State {
     /// Need to read the magic.
@@ -18,7 +18,7 @@ enum State {
         offset: usize,
     },
     /// The encrypted magic has been read, and now the unencrypted rest of the file must be read.
-    Body,
+    Body { key: [u8; TRANSFORMED_KEY_LEN] },
 }
 
 /// A struct to decrypt from a reader.
@@ -36,6 +36,16 @@ impl<R> Reader<R> {
             state: State::Magic,
         }
     }
+
+    /// Get the encryption key, in its transformed state.
+    ///
+    /// Returns `None` if the key has not been determined yet.
+    pub fn transformed_key(&self) -> Option<[u8; TRANSFORMED_KEY_LEN]> {
+        match self.state {
+            State::Magic | State::BodyStartNoKey => None,
+            State::BodyStart { key, .. } | State::Body { key } => Some(key),
+        }
+    }
 }
 
 impl<R> Reader<R>
@@ -46,7 +56,7 @@ where
     pub fn read_magic(&mut self) -> Result<(), Error> {
         match &self.state {
             State::Magic => {}
-            State::BodyStartNoKey | State::BodyStart { .. } | State::Body => return Ok(()),
+            State::BodyStartNoKey | State::BodyStart { .. } | State::Body { .. } => return Ok(()),
         }
 
         let mut buffer = [0; MAGIC_LEN];
@@ -64,11 +74,10 @@ where
     ///
     /// If the key has already been determined by any means, this is a nop.
     ///
-    /// This currently only works for encrypted pngs,
-    /// since they provide the minimum 16 bytes we need to recover the key.
-    ///
-    /// However, not all assets as pngs.
-    /// Some assets are oggs, which do not provide the minimum 16 bytes.
+    /// This works for encrypted pngs and oggs.
+    /// Oggs are detected by checking the crc of the first page,
+    /// which must be entirely present in the inner reader's buffer.
+    /// Anything else is assumed to be a png.
     pub fn guess_key(&mut self) -> Result<(), Error> {
         loop {
             match self.state {
@@ -76,33 +85,17 @@ where
                     self.read_magic()?;
                 }
                 State::BodyStartNoKey => break,
-                State::BodyStart { .. } | State::Body => {
+                State::BodyStart { .. } | State::Body { .. } => {
                     return Ok(());
                 }
             }
         }
 
         let buffer = self.reader.fill_buf()?;
-        let png_header = buffer
-            .get(..TRANSFORMED_KEY_LEN)
-            .ok_or(Error::BufferTooSmall)?;
-
-        let mut half_key_1 = [0; TRANSFORMED_KEY_LEN / 2];
-        for ((expected_png_byte, actual_png_byte), key_byte) in PNG_HEADER
-            .iter()
-            .copied()
-            .zip(png_header.iter().copied())
-            .zip(half_key_1.iter_mut())
-        {
-            *key_byte = actual_png_byte ^ expected_png_byte;
-        }
-
-        // To generate the full transformed key from the first 16 bytes of the transformed key,
-        // simply reverse the key and add it to a buffer.
-        let half_key_2: Vec<u8> = half_key_1.iter().copied().rev().collect();
-        let mut key = [0; TRANSFORMED_KEY_LEN];
-        key[..TRANSFORMED_KEY_LEN / 2].copy_from_slice(&half_key_1);
-        key[TRANSFORMED_KEY_LEN / 2..].copy_from_slice(&half_key_2);
+        let key = match guess_ogg_key(buffer) {
+            Some(key) => key,
+            None => guess_png_key(buffer).ok_or(Error::BufferTooSmall)?,
+        };
 
         self.state = State::BodyStart { key, offset: 0 };
 
@@ -114,20 +107,26 @@ where
     /// This will transform the key before use.
     /// This prevents the Reader from automatically guessing the key.
     pub fn set_key(&mut self, key: &str) -> Result<(), Error> {
+        self.set_transformed_key(transform_encryption_key(key))
+    }
+
+    /// Set the key manually, in its transformed state.
+    ///
+    /// This is useful for reusing a key guessed from one file for another.
+    /// This prevents the Reader from automatically guessing the key.
+    pub fn set_transformed_key(&mut self, key: [u8; TRANSFORMED_KEY_LEN]) -> Result<(), Error> {
         loop {
             match self.state {
                 State::Magic => {
                     self.read_magic()?;
                 }
                 State::BodyStartNoKey => break,
-                State::BodyStart { .. } | State::Body => {
+                State::BodyStart { .. } | State::Body { .. } => {
                     return Ok(());
                 }
             }
         }
 
-        let key = transform_encryption_key(key);
-
         self.state = State::BodyStart { key, offset: 0 };
 
         Ok(())
@@ -159,12 +158,12 @@ where
                     }
 
                     if *offset == TRANSFORMED_KEY_LEN {
-                        self.state = State::Body;
+                        self.state = State::Body { key: *key };
                     }
 
                     return Ok(n);
                 }
-                State::Body => {
+                State::Body { .. } => {
                     return self.reader.read(buffer);
                 }
             }
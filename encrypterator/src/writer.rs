@@ -0,0 +1,112 @@
+use crate::Error;
+use crate::MAGIC;
+use crate::TRANSFORMED_KEY_LEN;
+use crate::transform_encryption_key;
+
+#[derive(Debug)]
+enum State {
+    /// Need to write the magic.
+    Magic,
+    /// The magic has been written, and the encrypted part of the body must now be written.
+    BodyStart { offset: usize },
+    /// The encrypted part of the body has been written, and now the unencrypted rest must be written.
+    Body,
+}
+
+/// A struct to encrypt to a writer.
+#[derive(Debug)]
+pub struct Writer<W> {
+    writer: W,
+    key: [u8; TRANSFORMED_KEY_LEN],
+    state: State,
+}
+
+impl<W> Writer<W> {
+    /// Make a new Writer.
+    ///
+    /// This will transform the key before use.
+    pub fn new(writer: W, key: &str) -> Self {
+        Self {
+            writer,
+            key: transform_encryption_key(key),
+            state: State::Magic,
+        }
+    }
+
+    /// Make a new Writer with a key that is already transformed.
+    ///
+    /// This is useful for re-encrypting a file with a key guessed by a [`Reader`](crate::Reader).
+    pub fn with_transformed_key(writer: W, key: [u8; TRANSFORMED_KEY_LEN]) -> Self {
+        Self {
+            writer,
+            key,
+            state: State::Magic,
+        }
+    }
+
+    /// Get the inner writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W> Writer<W>
+where
+    W: std::io::Write,
+{
+    /// Write the magic, if it hasn't been done yet.
+    pub fn write_magic(&mut self) -> Result<(), Error> {
+        match &self.state {
+            State::Magic => {}
+            State::BodyStart { .. } | State::Body => return Ok(()),
+        }
+
+        self.writer.write_all(MAGIC)?;
+        self.state = State::BodyStart { offset: 0 };
+
+        Ok(())
+    }
+}
+
+impl<W> std::io::Write for Writer<W>
+where
+    W: std::io::Write,
+{
+    fn write(&mut self, buffer: &[u8]) -> std::io::Result<usize> {
+        loop {
+            match &mut self.state {
+                State::Magic => {
+                    self.write_magic().map_err(std::io::Error::other)?;
+                }
+                State::BodyStart { offset } => {
+                    let mut encrypted = [0; TRANSFORMED_KEY_LEN];
+                    let len = std::cmp::min(buffer.len(), TRANSFORMED_KEY_LEN - *offset);
+                    for ((out_byte, in_byte), key_byte) in encrypted
+                        .iter_mut()
+                        .zip(buffer[..len].iter().copied())
+                        .zip(self.key[*offset..].iter().copied())
+                    {
+                        *out_byte = in_byte ^ key_byte;
+                    }
+
+                    // Write everything, so that the offset always matches the inner writer.
+                    self.writer.write_all(&encrypted[..len])?;
+                    *offset += len;
+
+                    if *offset == TRANSFORMED_KEY_LEN {
+                        self.state = State::Body;
+                    }
+
+                    return Ok(len);
+                }
+                State::Body => {
+                    return self.writer.write(buffer);
+                }
+            }
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
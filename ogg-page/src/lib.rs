@@ -0,0 +1,80 @@
+/// The first bytes of the first page of an ogg file.
+///
+/// This is the capture pattern, the stream structure version,
+/// the "beginning of stream" header type flag, and a zero granule position.
+pub const FIRST_PAGE_HEADER: &[u8] = b"OggS\0\x02\0\0\0\0\0\0\0\0";
+
+/// The offset of the stream serial number in an ogg page header.
+pub const SERIAL_OFFSET: usize = 14;
+
+/// The offset of the page sequence number in an ogg page header.
+pub const PAGE_SEQUENCE_OFFSET: usize = 18;
+
+/// The offset of the crc in an ogg page header.
+pub const CRC_OFFSET: usize = 22;
+
+/// The offset of the segment count in an ogg page header.
+pub const SEGMENT_COUNT_OFFSET: usize = 26;
+
+/// The offset of the segment table in an ogg page header.
+pub const SEGMENT_TABLE_OFFSET: usize = 27;
+
+/// The crc lookup table used by ogg.
+const CRC_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = (i as u32) << 24;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 0x8000_0000 != 0 {
+                (crc << 1) ^ 0x04C1_1DB7
+            } else {
+                crc << 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// Update an ogg crc with more data.
+///
+/// The crc should start at 0.
+pub fn crc_update(mut crc: u32, data: &[u8]) -> u32 {
+    for byte in data.iter().copied() {
+        let index = ((crc >> 24) as u8) ^ byte;
+        crc = (crc << 8) ^ CRC_TABLE[usize::from(index)];
+    }
+    crc
+}
+
+/// Calculate the crc of an ogg page.
+///
+/// The crc field of the page should be zeroed.
+pub fn crc(page: &[u8]) -> u32 {
+    crc_update(0, page)
+}
+
+/// Find the stream serial number of an ogg page by checking candidates against the page crc.
+///
+/// Every byte of the page except the serial number must already be decrypted.
+/// Returns the first candidate that matches,
+/// or `None` if there are no matches or the page is too short.
+pub fn find_serial<I>(page: &[u8], candidates: I) -> Option<[u8; 4]>
+where
+    I: IntoIterator<Item = [u8; 4]>,
+{
+    let expected_crc = u32::from_le_bytes(page.get(CRC_OFFSET..CRC_OFFSET + 4)?.try_into().ok()?);
+
+    let prefix_crc = crc_update(0, page.get(..SERIAL_OFFSET)?);
+    let mut suffix = page.get(PAGE_SEQUENCE_OFFSET..)?.to_vec();
+    suffix[CRC_OFFSET - PAGE_SEQUENCE_OFFSET..][..4].fill(0);
+
+    candidates.into_iter().find(|serial| {
+        let crc = crc_update(prefix_crc, serial);
+        crc_update(crc, &suffix) == expected_crc
+    })
+}
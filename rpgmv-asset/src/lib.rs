@@ -0,0 +1,156 @@
+mod reader;
+
+pub use self::reader::Reader;
+
+const PNG_MAGIC: &[u8] = b"\x89PNG\r\n\x1a\n";
+const JPEG_MAGIC: &[u8] = &[0xff, 0xd8, 0xff];
+const OGG_MAGIC: &[u8] = b"OggS";
+const M4A_FTYP: &[u8] = b"ftyp";
+
+/// The number of bytes needed to detect any asset format.
+pub const DETECT_LEN: usize = encrypterator::MAGIC_LEN;
+
+/// The error type
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("io error")]
+    Io(#[from] std::io::Error),
+
+    #[error("unknown asset format")]
+    UnknownFormat,
+
+    #[error("rpgmvp error")]
+    Rpgmvp(#[from] rpgmvp::Error),
+
+    #[error("encrypterator error")]
+    Encrypterator(#[from] encrypterator::Error),
+}
+
+/// The format of an asset file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AssetFormat {
+    /// An unencrypted png
+    Png,
+
+    /// An unencrypted jpeg
+    Jpeg,
+
+    /// An unencrypted ogg
+    Ogg,
+
+    /// An unencrypted m4a
+    M4a,
+
+    /// A file encrypted by RPGMaker MV or MZ.
+    ///
+    /// These use the same format.
+    RpgMaker,
+
+    /// A file encrypted by Encrypterator 3000.
+    Encrypterator,
+}
+
+impl AssetFormat {
+    /// Detect the format of an asset from the start of the file.
+    ///
+    /// This needs at most [`DETECT_LEN`] bytes.
+    pub fn detect(prefix: &[u8]) -> Option<Self> {
+        if prefix.starts_with(PNG_MAGIC) {
+            Some(Self::Png)
+        } else if prefix.starts_with(JPEG_MAGIC) {
+            Some(Self::Jpeg)
+        } else if prefix.starts_with(OGG_MAGIC) {
+            Some(Self::Ogg)
+        } else if prefix.get(4..8) == Some(M4A_FTYP) {
+            Some(Self::M4a)
+        } else if prefix.starts_with(rpgmvp::HeaderSpec::MV.magic()) {
+            Some(Self::RpgMaker)
+        } else if prefix.starts_with(encrypterator::MAGIC) {
+            Some(Self::Encrypterator)
+        } else {
+            None
+        }
+    }
+
+    /// Detect the format of an asset file at the given path.
+    pub fn detect_path<P>(path: P) -> Result<Option<Self>, Error>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        use std::io::Read;
+
+        let file = std::fs::File::open(path)?;
+        let mut prefix = Vec::with_capacity(DETECT_LEN);
+        file.take(DETECT_LEN as u64).read_to_end(&mut prefix)?;
+
+        Ok(Self::detect(&prefix))
+    }
+
+    /// Check if this format is encrypted.
+    pub fn is_encrypted(self) -> bool {
+        matches!(self, Self::RpgMaker | Self::Encrypterator)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Read;
+
+    const ENCRYPTED: &[u8] = include_bytes!("../../rpgmvp/test_data/Actor1.rpgmvp");
+
+    #[test]
+    fn detect() {
+        assert!(AssetFormat::detect(ENCRYPTED) == Some(AssetFormat::RpgMaker));
+        assert!(AssetFormat::detect(encrypterator::MAGIC) == Some(AssetFormat::Encrypterator));
+        assert!(AssetFormat::detect(b"\xff\xd8\xff\xe0") == Some(AssetFormat::Jpeg));
+        assert!(AssetFormat::detect(b"\0\0\0\x1cftypM4A ") == Some(AssetFormat::M4a));
+        assert!(AssetFormat::detect(b"OggS\0\x02") == Some(AssetFormat::Ogg));
+        assert!(AssetFormat::detect(b"???").is_none());
+    }
+
+    #[test]
+    fn reader() {
+        let mut expected = Vec::new();
+        rpgmvp::Reader::new(ENCRYPTED)
+            .read_to_end(&mut expected)
+            .expect("failed to read");
+
+        let mut reader = Reader::new(ENCRYPTED).expect("failed to detect format");
+        assert!(reader.format() == AssetFormat::RpgMaker);
+        let mut decrypted = Vec::new();
+        reader.read_to_end(&mut decrypted).expect("failed to read");
+        assert!(decrypted == expected);
+
+        let mut reader = Reader::new(expected.as_slice()).expect("failed to detect format");
+        assert!(reader.format() == AssetFormat::Png);
+        let mut plain = Vec::new();
+        reader.read_to_end(&mut plain).expect("failed to read");
+        assert!(plain == expected);
+    }
+
+    #[test]
+    fn verify_rpgmaker_key() {
+        let key = rpgmvp::Reader::new(ENCRYPTED)
+            .extract_key()
+            .expect("failed to extract key");
+
+        let mut reader = Reader::new(ENCRYPTED).expect("failed to detect format");
+        let verified_key = reader
+            .verify_rpgmaker_key(rpgmvp::FileKind::Png)
+            .expect("failed to verify key");
+        assert!(verified_key == Some(rpgmvp::Key::new(key)));
+
+        let mut reader = Reader::new(ENCRYPTED).expect("failed to detect format");
+        reader
+            .set_rpgmaker_key(rpgmvp::Key::new([0; rpgmvp::KEY_LEN]))
+            .expect("failed to set key");
+        assert!(reader.verify_rpgmaker_key(rpgmvp::FileKind::Png).is_err());
+
+        let mut reader = Reader::new(b"OggS\0\x02".as_slice()).expect("failed to detect format");
+        let verified_key = reader
+            .verify_rpgmaker_key(rpgmvp::FileKind::Ogg)
+            .expect("failed to verify key");
+        assert!(verified_key.is_none());
+    }
+}
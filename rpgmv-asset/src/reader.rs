@@ -0,0 +1,138 @@
+use crate::AssetFormat;
+use crate::Error;
+use std::io::BufRead;
+use std::io::Read;
+
+/// The underlying reader for an asset format.
+enum Inner<R> {
+    Plain(R),
+    RpgMaker(rpgmvp::Reader<R>),
+    Encrypterator(encrypterator::Reader<R>),
+}
+
+/// A reader for any kind of asset.
+///
+/// This detects the asset format, and decrypts it if needed.
+pub struct Reader<R> {
+    inner: Inner<R>,
+    format: AssetFormat,
+    has_key: bool,
+}
+
+impl<R> Reader<R>
+where
+    R: BufRead,
+{
+    /// Create a new reader, detecting the asset format.
+    pub fn new(mut reader: R) -> Result<Self, Error> {
+        let prefix = reader.fill_buf()?;
+        let format = AssetFormat::detect(prefix).ok_or(Error::UnknownFormat)?;
+
+        let inner = match format {
+            AssetFormat::Png | AssetFormat::Jpeg | AssetFormat::Ogg | AssetFormat::M4a => {
+                Inner::Plain(reader)
+            }
+            AssetFormat::RpgMaker => Inner::RpgMaker(rpgmvp::Reader::new(reader)),
+            AssetFormat::Encrypterator => Inner::Encrypterator(encrypterator::Reader::new(reader)),
+        };
+
+        Ok(Self {
+            inner,
+            format,
+            has_key: !format.is_encrypted(),
+        })
+    }
+
+    /// Get the detected asset format.
+    pub fn format(&self) -> AssetFormat {
+        self.format
+    }
+
+    /// Set the key for RPGMaker files.
+    ///
+    /// This is a nop for other formats.
+    pub fn set_rpgmaker_key(&mut self, key: rpgmvp::Key) -> Result<(), Error> {
+        if let Inner::RpgMaker(reader) = &mut self.inner {
+            reader.set_key(key.into_bytes())?;
+            self.has_key = true;
+        }
+
+        Ok(())
+    }
+
+    /// Check that the key of an RPGMaker file decrypts it into the given file kind.
+    ///
+    /// If no key has been set, it is recovered from the file kind.
+    /// This must be called before reading.
+    /// Returns the key, or `None` for other formats.
+    pub fn verify_rpgmaker_key(
+        &mut self,
+        kind: rpgmvp::FileKind,
+    ) -> Result<Option<rpgmvp::Key>, Error> {
+        let Inner::RpgMaker(reader) = &mut self.inner else {
+            return Ok(None);
+        };
+
+        reader.verify_key(kind)?;
+        let key = reader
+            .recover_key(kind.known_plaintext())?
+            .to_key()
+            .ok_or(rpgmvp::Error::MissingKey)?;
+        self.has_key = true;
+
+        Ok(Some(rpgmvp::Key::new(key)))
+    }
+
+    /// Set the passphrase for Encrypterator files.
+    ///
+    /// This is a nop for other formats.
+    pub fn set_encrypterator_key(&mut self, key: &str) -> Result<(), Error> {
+        if let Inner::Encrypterator(reader) = &mut self.inner {
+            reader.set_key(key)?;
+            self.has_key = true;
+        }
+
+        Ok(())
+    }
+
+    /// Determine the key from the file itself, if it has not been set.
+    ///
+    /// For RPGMaker files, oggs are detected by their page crc.
+    /// Anything else is assumed to be a png.
+    /// This is called automatically before reading.
+    pub fn guess_key(&mut self) -> Result<(), Error> {
+        if self.has_key {
+            return Ok(());
+        }
+
+        match &mut self.inner {
+            Inner::Plain(_) => {}
+            Inner::RpgMaker(reader) => {
+                if !reader.recover_key(rpgmvp::OggPlaintext)?.is_complete() {
+                    reader.extract_key()?;
+                }
+            }
+            Inner::Encrypterator(reader) => {
+                reader.guess_key()?;
+            }
+        }
+        self.has_key = true;
+
+        Ok(())
+    }
+}
+
+impl<R> Read for Reader<R>
+where
+    R: BufRead,
+{
+    fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+        self.guess_key().map_err(std::io::Error::other)?;
+
+        match &mut self.inner {
+            Inner::Plain(reader) => reader.read(buffer),
+            Inner::RpgMaker(reader) => reader.read(buffer),
+            Inner::Encrypterator(reader) => reader.read(buffer),
+        }
+    }
+}
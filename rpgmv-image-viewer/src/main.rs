@@ -32,17 +32,12 @@ use egui_toast::ToastKind;
 use egui_toast::ToastOptions;
 use egui_toast::Toasts;
 use std::io::Read;
-use std::io::Seek;
-use std::io::SeekFrom;
 use std::path::Path;
 use std::path::PathBuf;
 
 const GIT_REV: &str = env!("GIT_REV");
 
 const TITLE: &str = "RPGMaker Image Viewer";
-const PNG_MAGIC: &[u8] = b"\x89PNG\r\n\x1a\n";
-const JPEG_MAGIC: &[u8] = &[0xff, 0xd8, 0xff];
-const ENCRYPTERATOR_MAGIC: &[u8] = b"ART\0ENCRYPTER100FREE\0VERSION\0\0\0\0";
 
 const RPGMV_PNG_EXTENSION: &str = "rpgmvp";
 const RPGMZ_PNG_EXTENSION: &str = "png_";
@@ -73,37 +68,26 @@ fn load_image(ctx: &egui::Context, path: &Path) -> anyhow::Result<Image> {
     let file_name_os_str = path.file_name().context("missing file name")?;
     let file_name = file_name_os_str.to_string_lossy().to_lowercase();
 
-    let mut file = std::fs::File::open(path)?;
+    let file = std::fs::File::open(path)?;
     let metadata = file.metadata()?;
 
-    let mut magic = [0; 32];
-    file.read_exact(&mut magic)?;
-    file.seek(SeekFrom::Start(0))?;
-
     let mut file = std::io::BufReader::new(file);
-    let rgba8_image = if magic.starts_with(PNG_MAGIC) || magic.starts_with(JPEG_MAGIC) {
-        let mut raw_image = Vec::with_capacity(usize::try_from(metadata.len())?);
-        file.read_to_end(&mut raw_image)?;
-
-        let image = image::load_from_memory(&raw_image)?;
-        image.into_rgba8()
-    } else if magic.starts_with(ENCRYPTERATOR_MAGIC) {
-        let mut encrypted_reader = encrypterator::Reader::new(file);
-        encrypted_reader.guess_key()?;
-
-        let mut raw_image = Vec::with_capacity(usize::try_from(metadata.len())?);
-        encrypted_reader.read_to_end(&mut raw_image)?;
-
-        let image = image::load_from_memory(&raw_image)?;
-        image.into_rgba8()
-    } else {
-        let mut encrypted_reader = rpgmvp::Reader::new(file);
-        let mut raw_image = Vec::with_capacity(usize::try_from(metadata.len())?);
-        encrypted_reader.read_to_end(&mut raw_image)?;
-
-        let image = image::load_from_memory(&raw_image)?;
-        image.into_rgba8()
-    };
+    let mut raw_image = Vec::with_capacity(usize::try_from(metadata.len())?);
+    match rpgmv_asset::Reader::new(&mut file) {
+        Ok(mut reader) => {
+            reader.read_to_end(&mut raw_image)?;
+        }
+        Err(rpgmv_asset::Error::UnknownFormat) => {
+            // Some games patch the header, so try to decrypt it anyways.
+            let mut reader = rpgmvp::Reader::new(file);
+            reader.set_lenient(true);
+            reader.read_to_end(&mut raw_image)?;
+        }
+        Err(error) => return Err(error.into()),
+    }
+
+    let image = image::load_from_memory(&raw_image)?;
+    let rgba8_image = image.into_rgba8();
 
     let image_size = [rgba8_image.width() as _, rgba8_image.height() as _];
     let pixels = rgba8_image.as_flat_samples();
@@ -8,7 +8,6 @@ use crate::parse_map_name;
 use anyhow::Context;
 use regex::Regex;
 use rpgmv_types::Plugin;
-use rpgmv_types::System;
 use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::path::Path;
@@ -24,6 +23,29 @@ const MESSAGE_FACE_PADDING: u16 = 12;
 /// Text padding is applied in addition to STANDARD_PADDING, but not for all windows.
 const TEXT_PADDING: u16 = 6;
 
+/// The parts of an MZ `System.json` needed to check line sizes.
+///
+/// Every other field is ignored,
+/// so this accepts any version of the file.
+#[derive(Debug, serde::Deserialize)]
+struct System {
+    /// This field is MZ only.
+    advanced: Option<SystemAdvanced>,
+}
+
+/// The parts of the MZ `advanced` system settings needed to check line sizes.
+#[derive(Debug, serde::Deserialize)]
+struct SystemAdvanced {
+    #[serde(rename = "mainFontFilename")]
+    main_font_filename: String,
+
+    #[serde(rename = "fontSize")]
+    font_size: u16,
+
+    #[serde(rename = "screenWidth")]
+    screen_width: u16,
+}
+
 fn read_to_string<P>(path: P) -> anyhow::Result<String>
 where
     P: AsRef<Path>,
@@ -705,3 +727,32 @@ pub fn check_line_size(options: &CheckLineSizeOptions) -> anyhow::Result<CheckLi
 
     Ok(iter)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_system() {
+        // Only the fields that are used are required.
+        let system: System = serde_json::from_str(
+            r#"{
+                "advanced": {
+                    "mainFontFilename": "mplus-1m-regular.woff",
+                    "fontSize": 26,
+                    "screenWidth": 816,
+                    "newField": true
+                },
+                "newField": true
+            }"#,
+        )
+        .expect("failed to parse");
+        let advanced = system.advanced.expect("missing advanced");
+        assert!(advanced.main_font_filename == "mplus-1m-regular.woff");
+        assert!(advanced.font_size == 26);
+        assert!(advanced.screen_width == 816);
+
+        let system: System = serde_json::from_str("{}").expect("failed to parse");
+        assert!(system.advanced.is_none());
+    }
+}
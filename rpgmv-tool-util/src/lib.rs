@@ -1,6 +1,7 @@
 mod check_line_size;
 mod font;
 pub mod message_parser;
+pub mod note_parser;
 mod util;
 
 pub use self::check_line_size::CheckLineSizeEntry;
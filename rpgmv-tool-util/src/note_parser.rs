@@ -0,0 +1,327 @@
+use std::ops::Range;
+
+/// The contents of a note tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteTagKind<'a> {
+    /// A tag without a value, like `<Tag>`.
+    Flag,
+
+    /// A tag with a value, like `<Tag: value>`.
+    ///
+    /// The value has surrounding whitespace trimmed.
+    Value(&'a str),
+
+    /// A tag with a body, like `<Tag>...</Tag>`.
+    ///
+    /// The body is everything between the opening and closing tags, including newlines.
+    Block(&'a str),
+}
+
+/// A tag in a note.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteTag<'a> {
+    /// The tag name
+    pub name: &'a str,
+
+    /// The tag contents
+    pub kind: NoteTagKind<'a>,
+
+    /// The byte range of the entire tag in the note.
+    ///
+    /// For blocks, this includes the closing tag.
+    pub span: Range<usize>,
+}
+
+impl<'a> NoteTag<'a> {
+    /// Get the value of this tag, if it has one.
+    pub fn value(&self) -> Option<&'a str> {
+        match self.kind {
+            NoteTagKind::Value(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Get the body of this tag, if it is a block.
+    pub fn body(&self) -> Option<&'a str> {
+        match self.kind {
+            NoteTagKind::Block(body) => Some(body),
+            _ => None,
+        }
+    }
+}
+
+/// A parsed note.
+///
+/// Tags are parsed like RPGMaker's `DataManager.extractMetadata`,
+/// with additional support for the `<Tag>...</Tag>` blocks used by many plugins.
+/// Tags inside of a block are not parsed.
+/// Parsing never fails, as anything that is not a tag is treated as plain text.
+#[derive(Debug)]
+pub struct Note<'a> {
+    input: &'a str,
+    tags: Vec<NoteTag<'a>>,
+}
+
+impl<'a> Note<'a> {
+    /// Parse a note.
+    pub fn parse(input: &'a str) -> Self {
+        let mut tags = Vec::new();
+        let mut index = 0;
+        while let Some(offset) = input[index..].find('<') {
+            let start = index + offset;
+            let name_start = start + 1;
+            let name_len = match input[name_start..].find(['<', '>', ':']) {
+                Some(name_len) => name_len,
+                None => break,
+            };
+            let name = &input[name_start..name_start + name_len];
+            let name_end = name_start + name_len;
+
+            if name.is_empty() {
+                index = name_start;
+                continue;
+            }
+
+            match input.as_bytes()[name_end] {
+                b'>' => {
+                    let end = name_end + 1;
+
+                    // A closing tag without an opening tag is plain text.
+                    if name.starts_with('/') {
+                        index = end;
+                        continue;
+                    }
+
+                    match find_closing_tag(input, end, name) {
+                        Some((close_start, close_end)) => {
+                            tags.push(NoteTag {
+                                name,
+                                kind: NoteTagKind::Block(&input[end..close_start]),
+                                span: start..close_end,
+                            });
+                            index = close_end;
+                        }
+                        None => {
+                            tags.push(NoteTag {
+                                name,
+                                kind: NoteTagKind::Flag,
+                                span: start..end,
+                            });
+                            index = end;
+                        }
+                    }
+                }
+                b':' => {
+                    let value_start = name_end + 1;
+                    let value_len = match input[value_start..].find('>') {
+                        Some(value_len) => value_len,
+                        None => break,
+                    };
+                    let value = &input[value_start..value_start + value_len];
+                    let end = value_start + value_len + 1;
+
+                    tags.push(NoteTag {
+                        name,
+                        kind: NoteTagKind::Value(value.trim()),
+                        span: start..end,
+                    });
+                    index = end;
+                }
+                _ => {
+                    // Another tag started before this one ended.
+                    index = name_end;
+                }
+            }
+        }
+
+        Self { input, tags }
+    }
+
+    /// Get the raw note.
+    pub fn as_str(&self) -> &'a str {
+        self.input
+    }
+
+    /// Get all tags, in the order they appear in the note.
+    pub fn tags(&self) -> &[NoteTag<'a>] {
+        &self.tags
+    }
+
+    /// Get the first tag with the given name.
+    ///
+    /// Names are compared case-insensitively.
+    pub fn get(&self, name: &str) -> Option<&NoteTag<'a>> {
+        self.tags
+            .iter()
+            .find(|tag| tag.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Get all tags with the given name.
+    ///
+    /// Names are compared case-insensitively.
+    pub fn get_all<'b>(&'b self, name: &'b str) -> impl Iterator<Item = &'b NoteTag<'a>> + 'b {
+        self.tags
+            .iter()
+            .filter(move |tag| tag.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Check if the note has a tag with the given name.
+    ///
+    /// Names are compared case-insensitively.
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// Make a new note where the first tag with the given name is replaced.
+    ///
+    /// If no tag has the name, the new tag is added on a new line at the end.
+    /// The rest of the note is left as-is.
+    pub fn set_tag(&self, name: &str, kind: NoteTagKind<'_>) -> String {
+        let new_tag = format_tag(name, kind);
+
+        match self.get(name) {
+            Some(tag) => {
+                let mut output = String::with_capacity(self.input.len() + new_tag.len());
+                output.push_str(&self.input[..tag.span.start]);
+                output.push_str(&new_tag);
+                output.push_str(&self.input[tag.span.end..]);
+                output
+            }
+            None => {
+                let mut output = self.input.to_string();
+                if !output.is_empty() && !output.ends_with('\n') {
+                    output.push('\n');
+                }
+                output.push_str(&new_tag);
+                output
+            }
+        }
+    }
+
+    /// Make a new note where the first tag with the given name is removed.
+    ///
+    /// If the tag was on its own line, the line is removed too.
+    /// The rest of the note is left as-is.
+    pub fn remove_tag(&self, name: &str) -> String {
+        let tag = match self.get(name) {
+            Some(tag) => tag,
+            None => return self.input.to_string(),
+        };
+
+        let before = &self.input[..tag.span.start];
+        let after = &self.input[tag.span.end..];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        let line_end = after.find('\n').unwrap_or(after.len());
+        let is_own_line =
+            before[line_start..].trim().is_empty() && after[..line_end].trim().is_empty();
+
+        if !is_own_line {
+            return format!("{before}{after}");
+        }
+
+        let before = &before[..line_start];
+        match after.get(line_end + 1..) {
+            Some(after) => format!("{before}{after}"),
+            // This was the last line, so remove the newline before it instead.
+            None => before.strip_suffix('\n').unwrap_or(before).to_string(),
+        }
+    }
+}
+
+/// Find the closing tag for a block, starting the search at the given index.
+///
+/// Returns the start and end of the closing tag.
+fn find_closing_tag(input: &str, mut index: usize, name: &str) -> Option<(usize, usize)> {
+    while let Some(offset) = input[index..].find("</") {
+        let start = index + offset;
+        let name_start = start + 2;
+        let name_end = name_start + name.len();
+
+        let is_match = input
+            .get(name_start..name_end)
+            .is_some_and(|close_name| close_name.eq_ignore_ascii_case(name))
+            && input.as_bytes().get(name_end) == Some(&b'>');
+        if is_match {
+            return Some((start, name_end + 1));
+        }
+
+        index = name_start;
+    }
+
+    None
+}
+
+/// Format a note tag.
+fn format_tag(name: &str, kind: NoteTagKind<'_>) -> String {
+    match kind {
+        NoteTagKind::Flag => format!("<{name}>"),
+        NoteTagKind::Value(value) => format!("<{name}: {value}>"),
+        NoteTagKind::Block(body) => format!("<{name}>{body}</{name}>"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let input = "A note.\n<Boss>\n<Price: 100 >\n<Custom Effect>\nvalue = <x>;\n</custom effect>\n</Stray>";
+        let note = Note::parse(input);
+
+        let tags = note.tags();
+        assert!(tags.len() == 3, "{tags:#?}");
+
+        assert!(tags[0].name == "Boss");
+        assert!(tags[0].kind == NoteTagKind::Flag);
+        assert!(&input[tags[0].span.clone()] == "<Boss>");
+
+        assert!(tags[1].name == "Price");
+        assert!(tags[1].value() == Some("100"));
+        assert!(&input[tags[1].span.clone()] == "<Price: 100 >");
+
+        assert!(tags[2].name == "Custom Effect");
+        assert!(tags[2].body() == Some("\nvalue = <x>;\n"));
+        assert!(input[tags[2].span.clone()].ends_with("</custom effect>"));
+
+        assert!(note.get("boss").is_some());
+        assert!(note.get("PRICE").and_then(|tag| tag.value()) == Some("100"));
+        assert!(!note.contains("x"));
+        assert!(!note.contains("/Stray"));
+    }
+
+    #[test]
+    fn parse_unclosed() {
+        let note = Note::parse("<<Tag>> <Other: 1");
+        let tags = note.tags();
+
+        assert!(tags.len() == 1, "{tags:#?}");
+        assert!(tags[0].name == "Tag");
+        assert!(tags[0].span == (1..6));
+    }
+
+    #[test]
+    fn set_tag() {
+        let note = Note::parse("Line 1\n<price: 100>\nLine 3");
+        assert!(note.set_tag("Price", NoteTagKind::Value("200")) == "Line 1\n<Price: 200>\nLine 3");
+        assert!(note.set_tag("Boss", NoteTagKind::Flag) == "Line 1\n<price: 100>\nLine 3\n<Boss>");
+
+        let note = Note::parse("");
+        assert!(note.set_tag("Boss", NoteTagKind::Block("\na\n")) == "<Boss>\na\n</Boss>");
+    }
+
+    #[test]
+    fn remove_tag() {
+        let note = Note::parse("Line 1\n<Boss>\nLine 3");
+        assert!(note.remove_tag("boss") == "Line 1\nLine 3");
+
+        let note = Note::parse("Line 1\n<Boss>");
+        assert!(note.remove_tag("boss") == "Line 1");
+
+        let note = Note::parse("Line <Boss> 1");
+        assert!(note.remove_tag("boss") == "Line  1");
+
+        let note = Note::parse("Line 1");
+        assert!(note.remove_tag("boss") == "Line 1");
+    }
+}
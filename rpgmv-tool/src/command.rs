@@ -1,5 +1,8 @@
+pub mod audit_keys;
 pub mod check_line_size;
 pub mod commands2py;
 pub mod decrypt;
+pub mod encrypt_game;
 pub mod encrypt_png;
 pub mod generate_completions;
+pub mod render_map;
@@ -0,0 +1,108 @@
+use crate::util::get_encryption_key;
+use crate::util::get_game_assets_dir;
+use crate::util::list_files;
+use crate::util::try_read_json;
+use anyhow::Context;
+use anyhow::bail;
+use clap::Parser;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+use std::path::PathBuf;
+
+#[derive(Debug, Parser)]
+#[command(about = "Check that every encrypted image in a game uses the same key")]
+pub struct Options {
+    #[arg(help = "The path to the game to audit", default_value = ".")]
+    pub input: PathBuf,
+}
+
+pub fn exec(options: Options) -> anyhow::Result<()> {
+    let root = get_game_assets_dir(&options.input)?;
+    let system_path = root.join("data").join("System.json");
+    let system_key = try_read_json(&system_path)?
+        .as_ref()
+        .map(get_encryption_key)
+        .transpose()?
+        .flatten();
+
+    let inputs = list_files(&root)?.into_iter().filter(|path| {
+        path.extension()
+            .and_then(|extension| extension.to_str())
+            .and_then(rpgmvp::FileKind::from_extension)
+            == Some(rpgmvp::FileKind::Png)
+    });
+
+    let mut keys: BTreeMap<rpgmvp::Key, Vec<PathBuf>> = BTreeMap::new();
+    let mut errors = Vec::new();
+    for input in inputs {
+        match extract_key(&input)? {
+            Ok(key) => {
+                keys.entry(key).or_default().push(input);
+            }
+            Err(error) => {
+                errors.push((input, error));
+            }
+        }
+    }
+
+    // If System.json has no key, assume the most common key is correct.
+    let expected_key = system_key.or_else(|| {
+        keys.iter()
+            .max_by_key(|(_key, files)| files.len())
+            .map(|(key, _files)| *key)
+    });
+
+    match system_key {
+        Some(key) => println!("System.json key: {key}"),
+        None => println!("System.json key: none"),
+    }
+
+    let mut num_outliers = 0;
+    for (key, files) in keys.iter() {
+        if Some(*key) == expected_key {
+            println!("Key {key}: {} file(s)", files.len());
+            continue;
+        }
+
+        num_outliers += files.len();
+        println!("Key {key}: {} file(s), outlier", files.len());
+        for file in files.iter() {
+            println!("  {}", file.display());
+        }
+    }
+
+    if !errors.is_empty() {
+        println!("Errors: {} file(s)", errors.len());
+        for (file, error) in errors.iter() {
+            println!("  {}: {error:?}", file.display());
+        }
+    }
+
+    if num_outliers != 0 || !errors.is_empty() {
+        bail!(
+            "found {num_outliers} file(s) with an outlier key and {} file(s) with errors",
+            errors.len()
+        );
+    }
+
+    Ok(())
+}
+
+/// Extract the key from an encrypted image.
+///
+/// The outer result is for io errors opening the file,
+/// while the inner result is for errors from the file's contents.
+fn extract_key(path: &Path) -> anyhow::Result<Result<rpgmvp::Key, rpgmvp::Error>> {
+    let file =
+        File::open(path).with_context(|| format!("failed to open \"{}\"", path.display()))?;
+    let mut reader = rpgmvp::Reader::new(BufReader::new(file));
+
+    let result = reader
+        .read_header()
+        .and_then(|()| reader.extract_key())
+        .map(rpgmvp::Key::new);
+
+    Ok(result)
+}
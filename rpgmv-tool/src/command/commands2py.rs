@@ -1,15 +1,7 @@
-mod command;
 mod config;
 mod file_sink;
 mod generate;
 
-use self::command::Command;
-use self::command::ConditionalBranchCommand;
-use self::command::ControlVariablesValue;
-use self::command::ControlVariablesValueGameData;
-use self::command::GetLocationInfoKind;
-use self::command::MaybeRef;
-use self::command::parse_event_command_list;
 use self::config::Config;
 use self::file_sink::FileSink;
 use self::generate::commands2py;
@@ -62,6 +54,12 @@ pub struct Options {
         help = "Check mtimes to skip assets that don't need to be converted again"
     )]
     use_mtimes: bool,
+
+    #[arg(
+        long = "tolerant",
+        help = "Ignore unknown fields in the input, instead of listing them and failing"
+    )]
+    tolerant: bool,
 }
 
 pub fn exec(options: Options) -> anyhow::Result<()> {
@@ -121,6 +119,7 @@ pub fn exec(options: Options) -> anyhow::Result<()> {
             &options.input,
             options.dry_run,
             options.overwrite,
+            options.tolerant,
             &config,
             largest_mtime,
             output,
@@ -144,6 +143,7 @@ pub fn exec(options: Options) -> anyhow::Result<()> {
                 output,
                 dry_run: options.dry_run,
                 overwrite: options.overwrite,
+                tolerant: options.tolerant,
             },
         )?;
     }
@@ -155,6 +155,7 @@ fn dump_dir(
     input: &Path,
     dry_run: bool,
     overwrite: bool,
+    tolerant: bool,
     config: &Config,
     largest_mtime: Option<SystemTime>,
     output: &Path,
@@ -204,7 +205,7 @@ fn dump_dir(
 
                 output.push(format!("{map_id:03}"));
 
-                let map: rpgmv_types::Map = serde_json::from_str(&input_str)
+                let map: rpgmv_types::Map = parse_json(&input_str, tolerant)
                     .with_context(|| format!("failed to parse \"{}\"", input.display()))?;
 
                 for (event_id, event) in map.events.iter().enumerate() {
@@ -243,6 +244,7 @@ fn dump_dir(
                                 output: &output,
                                 dry_run,
                                 overwrite,
+                                tolerant,
                             },
                         )?;
                     }
@@ -252,7 +254,7 @@ fn dump_dir(
                 output.push("common-events");
 
                 let common_events: Vec<Option<rpgmv_types::CommonEvent>> =
-                    serde_json::from_str(&input_str)
+                    parse_json(&input_str, tolerant)
                         .with_context(|| format!("failed to parse \"{}\"", input.display()))?;
 
                 for (common_event_id, common_event) in common_events.iter().enumerate() {
@@ -292,6 +294,7 @@ fn dump_dir(
                             output: &output,
                             dry_run,
                             overwrite,
+                            tolerant,
                         },
                     )?;
                 }
@@ -299,7 +302,7 @@ fn dump_dir(
             FileKind::Troops => {
                 output.push("troops");
 
-                let troops: Vec<Option<rpgmv_types::Troop>> = serde_json::from_str(&input_str)
+                let troops: Vec<Option<rpgmv_types::Troop>> = parse_json(&input_str, tolerant)
                     .with_context(|| format!("failed to parse \"{}\"", input.display()))?;
 
                 for (troop_id, troop) in troops.iter().enumerate() {
@@ -342,6 +345,7 @@ fn dump_dir(
                                 output: &output,
                                 dry_run,
                                 overwrite,
+                                tolerant,
                             },
                         )?;
                     }
@@ -367,6 +371,7 @@ struct DumpFileOptions<'a> {
     output: &'a Path,
     dry_run: bool,
     overwrite: bool,
+    tolerant: bool,
 }
 
 fn dump_file(
@@ -396,7 +401,7 @@ fn dump_file(
 
     let event_commands = match input_file_kind {
         FileKind::Map => {
-            let mut map: rpgmv_types::Map = serde_json::from_str(&input_str)
+            let mut map: rpgmv_types::Map = parse_json(&input_str, options.tolerant)
                 .with_context(|| format!("failed to parse \"{}\"", options.input.display()))?;
 
             let mut event = usize::try_from(options.id)
@@ -431,7 +436,7 @@ fn dump_file(
         }
         FileKind::CommonEvents => {
             let mut common_events: Vec<Option<rpgmv_types::CommonEvent>> =
-                serde_json::from_str(&input_str)
+                parse_json(&input_str, options.tolerant)
                     .with_context(|| format!("failed to parse \"{}\"", options.input.display()))?;
 
             let event = usize::try_from(options.id)
@@ -454,8 +459,9 @@ fn dump_file(
             event.list
         }
         FileKind::Troops => {
-            let mut troops: Vec<Option<rpgmv_types::Troop>> = serde_json::from_str(&input_str)
-                .with_context(|| format!("failed to parse \"{}\"", options.input.display()))?;
+            let mut troops: Vec<Option<rpgmv_types::Troop>> =
+                parse_json(&input_str, options.tolerant)
+                    .with_context(|| format!("failed to parse \"{}\"", options.input.display()))?;
 
             let mut troop = usize::try_from(options.id)
                 .ok()
@@ -491,8 +497,11 @@ fn dump_file(
         }
     };
 
-    let commands =
-        parse_event_command_list(&event_commands).context("failed to parse event command list")?;
+    let commands = event_commands
+        .iter()
+        .map(rpgmv_types::TypedCommand::try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .context("failed to parse event command list")?;
     let mut file_sink = FileSink::new(options.output, options.dry_run, options.overwrite)?;
 
     commands2py(options.config, &commands, &mut file_sink)?;
@@ -550,6 +559,20 @@ impl FileKind {
     }
 }
 
+/// Parse a json file.
+///
+/// Unless tolerant, this fails if there are any unknown fields.
+fn parse_json<T>(input: &str, tolerant: bool) -> anyhow::Result<T>
+where
+    T: serde::de::DeserializeOwned + rpgmv_types::UnknownFields,
+{
+    if tolerant {
+        Ok(serde_json::from_str(input)?)
+    } else {
+        Ok(rpgmv_types::from_str_strict(input)?)
+    }
+}
+
 /// Extracts the map number from a file name.
 ///
 /// # Returns
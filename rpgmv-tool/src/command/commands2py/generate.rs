@@ -2,58 +2,76 @@ mod function_call_writer;
 
 use self::function_call_writer::FunctionCallWriter;
 use self::function_call_writer::Ident;
-use super::Command;
-use super::ConditionalBranchCommand;
 use super::Config;
-use super::ControlVariablesValue;
-use super::ControlVariablesValueGameData;
-use super::GetLocationInfoKind;
-use super::MaybeRef;
+use anyhow::Context;
+use anyhow::bail;
 use anyhow::ensure;
+use rpgmv_types::ActorCheck;
+use rpgmv_types::CommandData;
+use rpgmv_types::Condition;
+use rpgmv_types::EnemyCheck;
+use rpgmv_types::Operand;
+use rpgmv_types::TypedCommand;
+use rpgmv_types::VariableOperand;
 use std::io::Write;
+use std::iter::Peekable;
+use std::slice::Iter;
 
 pub fn commands2py<W>(
     config: &Config,
-    commands: &[(u16, Command)],
+    commands: &[TypedCommand],
     mut writer: W,
 ) -> anyhow::Result<()>
 where
     W: Write,
 {
-    for (indent, command) in commands.iter() {
-        command2py(config, *indent, command, &mut writer)?;
+    let mut commands = commands.iter().peekable();
+    while let Some(command) = commands.next() {
+        command2py(config, command, &mut commands, &mut writer)?;
     }
 
     Ok(())
 }
 
+/// Write a command.
+///
+/// Commands that continue this one, like the lines of a text box, are taken from `commands`.
 fn command2py<W>(
     config: &Config,
-    indent: u16,
-    command: &Command,
+    command: &TypedCommand,
+    commands: &mut Peekable<Iter<'_, TypedCommand>>,
     mut writer: W,
 ) -> anyhow::Result<()>
 where
     W: Write,
 {
-    match command {
-        Command::Nop => {}
-        Command::ShowText {
+    let indent = command.indent;
+    match &command.data {
+        CommandData::Nop {} => {}
+        CommandData::ShowText {
             face_name,
             face_index,
             background,
             position_type,
-            lines,
+            speaker_name,
         } => {
+            let lines = take_lines(commands, |data| match data {
+                CommandData::TextData { line } => Some(line),
+                _ => None,
+            });
+
             let mut writer = FunctionCallWriter::new(&mut writer, indent, "show_text")?;
             writer.write_param("face_name", face_name)?;
             writer.write_param("face_index", face_index)?;
             writer.write_param("background", background)?;
             writer.write_param("position_type", position_type)?;
-            writer.write_param("lines", lines)?;
+            if let Some(speaker_name) = speaker_name {
+                writer.write_param("speaker_name", speaker_name)?;
+            }
+            writer.write_param("lines", &lines)?;
             writer.finish()?;
         }
-        Command::ShowChoices {
+        CommandData::ShowChoices {
             choices,
             cancel_type,
             default_type,
@@ -63,16 +81,22 @@ where
             let mut writer = FunctionCallWriter::new(&mut writer, indent, "show_choices")?;
             writer.write_param("choices", choices)?;
             writer.write_param("cancel_type", cancel_type)?;
-            writer.write_param("default_type", default_type)?;
-            writer.write_param("position_type", position_type)?;
-            writer.write_param("background", background)?;
+            if let Some(default_type) = default_type {
+                writer.write_param("default_type", default_type)?;
+            }
+            if let Some(position_type) = position_type {
+                writer.write_param("position_type", position_type)?;
+            }
+            if let Some(background) = background {
+                writer.write_param("background", background)?;
+            }
             writer.finish()?;
         }
-        Command::ShowScrollingText {
-            speed,
-            no_fast,
-            lines,
-        } => {
+        CommandData::ShowScrollingText { speed, no_fast } => {
+            let lines = take_lines(commands, |data| match data {
+                CommandData::ShowScrollingTextExtra { line } => Some(line),
+                _ => None,
+            });
             let no_fast = stringify_bool(*no_fast);
 
             write_indent(&mut writer, indent)?;
@@ -88,7 +112,7 @@ where
             writeln!(&mut writer, "lines=[")?;
 
             for line in lines {
-                let line = escape_string(line);
+                let line = escape_string(&line);
 
                 write_indent(&mut writer, indent + 2)?;
                 writeln!(&mut writer, "'{line}',")?;
@@ -100,44 +124,57 @@ where
             write_indent(&mut writer, indent)?;
             writeln!(&mut writer, ")")?;
         }
-        Command::Comment { lines } => {
-            for line in lines.iter() {
+        CommandData::Comment { line } => {
+            let lines = take_lines(commands, |data| match data {
+                CommandData::CommentExtra { line } => Some(line),
+                _ => None,
+            });
+
+            for line in std::iter::once(line).chain(lines.iter()) {
                 write_indent(&mut writer, indent)?;
                 writeln!(&mut writer, "# {line}")?;
             }
         }
-        Command::ConditionalBranch(command) => {
+        CommandData::ConditionalBranch { condition } => {
             write_indent(&mut writer, indent)?;
             write!(&mut writer, "if ")?;
-            match command {
-                ConditionalBranchCommand::Switch { id, check_true } => {
-                    let name = config.get_switch_name(*id);
-                    let check_true_str = if *check_true { "" } else { "not " };
+            match condition {
+                Condition::Switch { switch_id, value } => {
+                    let name = config.get_switch_name(*switch_id);
+                    let check_true_str = if *value == 0 { "" } else { "not " };
                     writeln!(&mut writer, "{check_true_str}{name}:")?;
                 }
-                ConditionalBranchCommand::Variable {
-                    lhs_id,
-                    rhs_id,
-                    operation,
+                Condition::Variable {
+                    variable_id,
+                    operand,
+                    comparison,
                 } => {
-                    let lhs = config.get_variable_name(*lhs_id);
-                    let rhs = match rhs_id {
-                        MaybeRef::Constant(value) => value.to_string(),
-                        MaybeRef::Ref(id) => config.get_variable_name(*id),
+                    let lhs = config.get_variable_name(*variable_id);
+                    let rhs = operand2py(config, operand);
+                    let comparison = match comparison {
+                        0 => "==",
+                        1 => ">=",
+                        2 => "<=",
+                        3 => ">",
+                        4 => "<",
+                        5 => "!=",
+                        _ => bail!("{comparison} is not a valid variable comparison"),
                     };
-                    let operation = operation.as_str();
 
-                    writeln!(&mut writer, "{lhs} {operation} {rhs}:")?;
+                    writeln!(&mut writer, "{lhs} {comparison} {rhs}:")?;
                 }
-                ConditionalBranchCommand::SelfSwitch { name, check_true } => {
-                    let name = escape_string(name);
-                    let check_true_str = if *check_true { "" } else { "not " };
+                Condition::SelfSwitch { key, value } => {
+                    let name = escape_string(key);
+                    let check_true_str = if *value == 0 { "" } else { "not " };
                     writeln!(
                         &mut writer,
                         "{check_true_str}game_self_switches.get(map_id=self.map_id, event_id=self.event_id, name='{name}'):"
                     )?;
                 }
-                ConditionalBranchCommand::ActorInParty { actor_id } => {
+                Condition::Actor {
+                    actor_id,
+                    check: ActorCheck::InParty,
+                } => {
                     let actor_name = config.get_actor_name(*actor_id);
 
                     writeln!(
@@ -145,38 +182,57 @@ where
                         "game_party.members.contains(actor={actor_name}):"
                     )?;
                 }
-                ConditionalBranchCommand::ActorName { actor_id, name } => {
+                Condition::Actor {
+                    actor_id,
+                    check: ActorCheck::Name(name),
+                } => {
                     let actor_name = config.get_actor_name(*actor_id);
                     let name = escape_string(name);
 
                     writeln!(&mut writer, "{actor_name}.name() == '{name}':")?;
                 }
-                ConditionalBranchCommand::Timer { value, is_gte } => {
-                    let cmp = if *is_gte { ">=" } else { "<=" };
+                Condition::Timer {
+                    seconds,
+                    comparison,
+                } => {
+                    let cmp = match comparison {
+                        0 => ">=",
+                        1 => "<=",
+                        _ => bail!("{comparison} is not a valid timer comparison"),
+                    };
 
-                    writeln!(&mut writer, "game_timer.seconds() {cmp} {value}:")?;
+                    writeln!(&mut writer, "game_timer.seconds() {cmp} {seconds}:")?;
                 }
-                ConditionalBranchCommand::ActorSkill { actor_id, skill_id } => {
+                Condition::Actor {
+                    actor_id,
+                    check: ActorCheck::Skill(skill_id),
+                } => {
                     let actor_name = config.get_actor_name(*actor_id);
                     let skill_name = config.get_skill_name(*skill_id);
 
                     writeln!(&mut writer, "{actor_name}.has_skill(skill={skill_name}):")?;
                 }
-                ConditionalBranchCommand::ActorArmor { actor_id, armor_id } => {
+                Condition::Actor {
+                    actor_id,
+                    check: ActorCheck::Armor(armor_id),
+                } => {
                     let actor_name = config.get_actor_name(*actor_id);
                     let armor_name = config.get_armor_name(*armor_id);
 
                     writeln!(&mut writer, "{actor_name}.has_armor(armor={armor_name}):")?;
                 }
-                ConditionalBranchCommand::ActorState { actor_id, state_id } => {
+                Condition::Actor {
+                    actor_id,
+                    check: ActorCheck::State(state_id),
+                } => {
                     let actor_name = config.get_actor_name(*actor_id);
                     let state_name = config.get_state_name(*state_id);
 
                     writeln!(&mut writer, "{actor_name}.has_state(state={state_name}):")?;
                 }
-                ConditionalBranchCommand::EnemyState {
+                Condition::Enemy {
                     enemy_index,
-                    state_id,
+                    check: EnemyCheck::State(state_id),
                 } => {
                     let name = config.get_state_name(*state_id);
 
@@ -185,7 +241,7 @@ where
                         "game_troop.members[{enemy_index}].is_state_affected(state={name}):"
                     )?;
                 }
-                ConditionalBranchCommand::Character {
+                Condition::Character {
                     character_id,
                     direction,
                 } => {
@@ -197,17 +253,22 @@ where
 
                     writeln!(&mut writer, "{name}.direction == {direction}:")?;
                 }
-                ConditionalBranchCommand::Gold { value, check } => {
-                    let check = check.as_str();
+                Condition::Gold { gold, comparison } => {
+                    let check = match comparison {
+                        0 => ">=",
+                        1 => "<=",
+                        2 => "<",
+                        _ => bail!("{comparison} is not a valid gold comparison"),
+                    };
 
-                    writeln!(&mut writer, "game_party.gold {check} {value}:")?;
+                    writeln!(&mut writer, "game_party.gold {check} {gold}:")?;
                 }
-                ConditionalBranchCommand::Item { item_id } => {
+                Condition::Item { item_id } => {
                     let name = config.get_item_name(*item_id);
 
                     writeln!(&mut writer, "game_party.has_item(item={name}):")?;
                 }
-                ConditionalBranchCommand::Weapon {
+                Condition::Weapon {
                     weapon_id,
                     include_equipped,
                 } => {
@@ -219,167 +280,126 @@ where
                         "game_party.has_weapon(weapon={name}, include_equipped={include_equipped}):"
                     )?;
                 }
-                ConditionalBranchCommand::Button { key_name } => {
-                    let key_name = escape_string(key_name);
+                Condition::Button { button, kind: None } => {
+                    let key_name = escape_string(button);
 
                     writeln!(&mut writer, "game_input.is_pressed(key_name='{key_name}'):")?;
                 }
-                ConditionalBranchCommand::Script { value } => {
-                    let value = escape_string(value);
+                Condition::Script { script } => {
+                    let value = escape_string(script);
 
                     writeln!(&mut writer, "execute_script('{value}'):")?;
                 }
+                _ => bail!("conditional branch condition {condition:?} is not supported"),
             }
         }
-        Command::Loop => {
+        CommandData::Loop {} => {
             write_indent(&mut writer, indent)?;
             writeln!(&mut writer, "while True:")?;
         }
-        Command::ExitEventProcessing => {
+        CommandData::ExitEventProcessing {} => {
             write_indent(&mut writer, indent)?;
             writeln!(&mut writer, "exit_event_processing()")?;
         }
-        Command::CommonEvent { id } => {
+        CommandData::CommonEvent { id } => {
             let name = config.get_common_event_name(*id);
             FunctionCallWriter::new(&mut writer, indent, &name)?.finish()?;
         }
-        Command::Label { name } => {
+        CommandData::Label { name } => {
             let mut writer = FunctionCallWriter::new(&mut writer, indent, "set_label")?;
             writer.set_multiline(false);
             writer.write_param("name", name)?;
             writer.finish()?;
         }
-        Command::JumpToLabel { name } => {
+        CommandData::JumpToLabel { name } => {
             let mut writer = FunctionCallWriter::new(&mut writer, indent, "jump_to_label")?;
             writer.set_multiline(false);
             writer.write_param("name", name)?;
             writer.finish()?;
         }
-        Command::ControlSwitches {
+        CommandData::ControlSwitches {
             start_id,
             end_id,
             value,
         } => {
             for id in *start_id..(*end_id + 1) {
                 let name = config.get_switch_name(id);
-                let value = stringify_bool(*value);
+                let value = stringify_bool(*value == 0);
 
                 write_indent(&mut writer, indent)?;
                 writeln!(&mut writer, "{name} = {value}")?;
             }
         }
-        Command::ControlVariables {
-            start_variable_id,
-            end_variable_id,
+        CommandData::ControlVariables {
+            start_id,
+            end_id,
             operation,
-            value,
+            operand,
         } => {
-            let operation = operation.as_str();
-            let value = match value {
-                ControlVariablesValue::Constant { value } => value.to_string(),
-                ControlVariablesValue::Variable { id } => config.get_variable_name(*id),
-                ControlVariablesValue::Random { start, stop } => {
-                    format!("random.randrange(start={start}, stop={stop})")
+            let operation = match operation {
+                0 => "=",
+                1 => "+=",
+                2 => "-=",
+                3 => "*=",
+                4 => "/=",
+                5 => "%=",
+                _ => bail!("{operation} is not a valid variable operation"),
+            };
+            let value = match operand {
+                VariableOperand::Constant(value) => value.to_string(),
+                VariableOperand::Variable(id) => config.get_variable_name(*id),
+                VariableOperand::Random { min, max } => {
+                    format!("random.randrange(start={min}, stop={max})")
                 }
-                ControlVariablesValue::GameData(game_data) => match game_data {
-                    ControlVariablesValueGameData::NumItems { item_id } => {
-                        let name = config.get_item_name(*item_id);
-
-                        format!("game_party.get_num_items(item={name})")
-                    }
-                    ControlVariablesValueGameData::ActorLevel { actor_id } => {
-                        let name = config.get_actor_name(*actor_id);
-                        format!("{name}.level")
-                    }
-                    ControlVariablesValueGameData::ActorExp { actor_id } => {
-                        let name = config.get_actor_name(*actor_id);
-                        format!("{name}.currentExp")
-                    }
-                    ControlVariablesValueGameData::ActorHp { actor_id } => {
-                        let name = config.get_actor_name(*actor_id);
-                        format!("{name}.hp")
-                    }
-                    ControlVariablesValueGameData::ActorMp { actor_id } => {
-                        let name = config.get_actor_name(*actor_id);
-                        format!("{name}.mp")
-                    }
-                    ControlVariablesValueGameData::ActorParam {
-                        actor_id,
-                        param_index,
-                    } => {
-                        let name = config.get_actor_name(*actor_id);
-                        format!("{name}.param({param_index})")
-                    }
-                    ControlVariablesValueGameData::EnemyParam {
-                        enemy_index,
-                        param_index,
-                    } => {
-                        format!("game_troop.members[{enemy_index}].param({param_index})")
-                    }
-                    ControlVariablesValueGameData::CharacterMapX { character_id } => {
-                        format!("game.get_character(id={character_id}).map_x")
-                    }
-                    ControlVariablesValueGameData::CharacterMapY { character_id } => {
-                        format!("game.get_character(id={character_id}).map_y")
-                    }
-                    ControlVariablesValueGameData::CharacterScreenX { character_id } => {
-                        format!("game.get_character(id={character_id}).screen_x")
-                    }
-                    ControlVariablesValueGameData::CharacterScreenY { character_id } => {
-                        format!("game.get_character(id={character_id}).screen_y")
-                    }
-                    ControlVariablesValueGameData::MapId => "game_map.map_id()".to_string(),
-                    ControlVariablesValueGameData::Gold => "game_party.gold".to_string(),
-                    ControlVariablesValueGameData::Steps => "game_party.steps".to_string(),
-                },
-                ControlVariablesValue::Script { value } => {
+                VariableOperand::GameData {
+                    kind,
+                    param1,
+                    param2,
+                } => game_data2py(config, *kind, *param1, *param2)?,
+                VariableOperand::Script(value) => {
                     let value = escape_string(value);
                     format!("execute_script('{value}')")
                 }
             };
-            for variable_id in *start_variable_id..(*end_variable_id + 1) {
+            for variable_id in *start_id..(*end_id + 1) {
                 let name = config.get_variable_name(variable_id);
 
                 write_indent(&mut writer, indent)?;
                 writeln!(&mut writer, "{name} {operation} {value}")?;
             }
         }
-        Command::ControlSelfSwitch { key, value } => {
-            let value = stringify_bool(*value);
+        CommandData::ControlSelfSwitch { key, value } => {
+            let value = stringify_bool(*value == 0);
 
             write_indent(&mut writer, indent)?;
             writeln!(&mut writer, "game_self_switches['{key}'] = {value}")?;
         }
-        Command::ControlTimer { start_seconds } => {
+        CommandData::ControlTimer { operation, seconds } => {
             write_indent(&mut writer, indent)?;
-            match start_seconds {
-                Some(start_seconds) => {
-                    writeln!(&mut writer, "game_timer.start(seconds={start_seconds})")?
+            match operation {
+                0 => {
+                    let seconds = seconds.context("missing timer seconds")?;
+                    writeln!(&mut writer, "game_timer.start(seconds={seconds})")?
                 }
-                None => writeln!(&mut writer, "game_timer.stop()")?,
+                1 => writeln!(&mut writer, "game_timer.stop()")?,
+                _ => bail!("{operation} is not a valid timer operation"),
             }
         }
-        Command::ChangeGold { is_add, value } => {
-            let op = if *is_add { "+=" } else { "-=" };
-            let value = match value {
-                MaybeRef::Constant(value) => value.to_string(),
-                MaybeRef::Ref(id) => config.get_variable_name(*id),
-            };
+        CommandData::ChangeGold { operation, value } => {
+            let op = if *operation == 0 { "+=" } else { "-=" };
+            let value = operand2py(config, value);
 
             write_indent(&mut writer, indent)?;
             writeln!(&mut writer, "game_party.gold {op} {value}")?;
         }
-        Command::ChangeItems {
+        CommandData::ChangeItems {
             item_id,
-            is_add,
+            operation,
             value,
         } => {
             let item = config.get_item_name(*item_id);
-            let sign = if *is_add { "" } else { "-" };
-            let value = match value {
-                MaybeRef::Constant(value) => value.to_string(),
-                MaybeRef::Ref(id) => config.get_variable_name(*id),
-            };
+            let sign = if *operation == 0 { "" } else { "-" };
+            let value = operand2py(config, value);
             let value = format!("{sign}{value}");
 
             let mut writer = FunctionCallWriter::new(&mut writer, indent, "gain_item")?;
@@ -388,34 +408,33 @@ where
             writer.write_param("value", &Ident(&value))?;
             writer.finish()?;
         }
-        Command::ChangeArmors {
+        CommandData::ChangeArmors {
             armor_id,
-            is_add,
+            operation,
             value,
             include_equipped,
         } => {
             let armor = config.get_armor_name(*armor_id);
-            let sign = if *is_add { "" } else { "-" };
-            let value = match value {
-                MaybeRef::Constant(value) => value.to_string(),
-                MaybeRef::Ref(id) => config.get_variable_name(*id),
-            };
+            let sign = if *operation == 0 { "" } else { "-" };
+            let value = operand2py(config, value);
             let value = format!("{sign}{value}");
+            let include_equipped = include_equipped.unwrap_or(false);
 
             let mut writer = FunctionCallWriter::new(&mut writer, indent, "gain_armor")?;
             writer.set_multiline(false);
             writer.write_param("armor", &Ident(&armor))?;
             writer.write_param("value", &Ident(&value))?;
-            writer.write_param("include_equipped", include_equipped)?;
+            writer.write_param("include_equipped", &include_equipped)?;
             writer.finish()?;
         }
-        Command::ChangePartyMember {
+        CommandData::ChangePartyMember {
             actor_id,
-            is_add,
+            operation,
             initialize,
         } => {
             let actor_name = config.get_actor_name(*actor_id);
-            let fn_name = if *is_add {
+            let is_add = *operation == 0;
+            let fn_name = if is_add {
                 "add_party_member"
             } else {
                 "remove_party_member"
@@ -425,13 +444,13 @@ where
             writer.set_multiline(false);
             writer.write_param("actor", &Ident(&actor_name))?;
             // The argument is always provided, but ignored by remove ops.
-            if *is_add {
+            if is_add {
                 writer.write_param("initialize", initialize)?;
             }
             writer.finish()?;
         }
-        Command::ChangeSaveAccess { disable } => {
-            let fn_name = if *disable {
+        CommandData::ChangeSaveAccess { value } => {
+            let fn_name = if *value == 0 {
                 "disable_saving"
             } else {
                 "enable_saving"
@@ -440,38 +459,33 @@ where
             let mut writer = FunctionCallWriter::new(&mut writer, indent, fn_name)?;
             writer.finish()?;
         }
-        Command::SetEventLocation {
+        CommandData::SetEventLocation {
             character_id,
+            designation,
             x,
             y,
             direction,
         } => {
+            let (x, y) = match designation {
+                0 => (x.to_string(), y.to_string()),
+                1 => (
+                    config.get_variable_name(u32::try_from(*x)?),
+                    config.get_variable_name(u32::try_from(*y)?),
+                ),
+                _ => bail!("event location designation {designation} is not supported"),
+            };
+
             let mut writer = FunctionCallWriter::new(&mut writer, indent, "set_event_location")?;
             writer.write_param("character_id", character_id)?;
-            match x {
-                MaybeRef::Constant(x) => {
-                    writer.write_param("x", x)?;
-                }
-                MaybeRef::Ref(x) => {
-                    let x = config.get_variable_name(*x);
-                    writer.write_param("x", &Ident(&x))?;
-                }
-            }
-            match y {
-                MaybeRef::Constant(y) => {
-                    writer.write_param("y", y)?;
-                }
-                MaybeRef::Ref(y) => {
-                    let y = config.get_variable_name(*y);
-                    writer.write_param("y", &Ident(&y))?;
-                }
-            }
-            if let Some(direction) = direction {
+            writer.write_param("x", &Ident(&x))?;
+            writer.write_param("y", &Ident(&y))?;
+            if *direction != 0 {
                 writer.write_param("direction", direction)?;
             }
             writer.finish()?;
         }
-        Command::TransferPlayer {
+        CommandData::TransferPlayer {
+            designation,
             map_id,
             x,
             y,
@@ -479,35 +493,24 @@ where
             fade_type,
         } => {
             let mut writer = FunctionCallWriter::new(&mut writer, indent, "transfer_player")?;
-            match map_id {
-                MaybeRef::Constant(id) => {
-                    let name = format!("game_map_{id}");
+            match designation {
+                0 => {
+                    let name = format!("game_map_{map_id}");
                     writer.write_param("map", &Ident(&name))?;
+                    writer.write_param("x", x)?;
+                    writer.write_param("y", y)?;
                 }
-                MaybeRef::Ref(id) => {
-                    let name = config.get_variable_name(*id);
+                1 => {
+                    let name = config.get_variable_name(*map_id);
                     writer.write_param("map_id", &Ident(&name))?;
-                }
-            }
 
-            match x {
-                MaybeRef::Constant(value) => {
-                    writer.write_param("x", value)?;
-                }
-                MaybeRef::Ref(id) => {
-                    let name = config.get_variable_name(*id);
+                    let name = config.get_variable_name(*x);
                     writer.write_param("x", &Ident(&name))?;
-                }
-            }
 
-            match y {
-                MaybeRef::Constant(value) => {
-                    writer.write_param("y", value)?;
-                }
-                MaybeRef::Ref(id) => {
-                    let name = config.get_variable_name(*id);
+                    let name = config.get_variable_name(*y);
                     writer.write_param("y", &Ident(&name))?;
                 }
+                _ => bail!("{designation} is not a valid transfer designation"),
             }
 
             writer.write_param("direction", direction)?;
@@ -515,17 +518,30 @@ where
 
             writer.finish()?;
         }
-        Command::SetMovementRoute {
+        CommandData::SetMovementRoute {
             character_id,
             route,
         } => {
+            // The editor repeats each move command after this one.
+            for move_command in route.list.iter() {
+                let Some(next) = commands.next_if(|command| {
+                    matches!(command.data, CommandData::SetMovementRouteExtra { .. })
+                }) else {
+                    break;
+                };
+                let CommandData::SetMovementRouteExtra { command } = &next.data else {
+                    unreachable!();
+                };
+                ensure!(command == move_command);
+            }
+
             let mut writer = FunctionCallWriter::new(&mut writer, indent, "set_movement_route")?;
             writer.write_param("character_id", character_id)?;
             writer.write_param("route", route)?;
             writer.finish()?;
         }
-        Command::ChangeTransparency { set_transparent } => {
-            let set_transparent = stringify_bool(*set_transparent);
+        CommandData::ChangeTransparency { value } => {
+            let set_transparent = stringify_bool(*value == 0);
 
             write_indent(&mut writer, indent)?;
             writeln!(
@@ -533,7 +549,7 @@ where
                 "change_transparency(set_transparent={set_transparent})"
             )?
         }
-        Command::ShowAnimation {
+        CommandData::ShowAnimation {
             character_id,
             animation_id,
             wait,
@@ -545,7 +561,7 @@ where
             writer.write_param("wait", wait)?;
             writer.finish()?;
         }
-        Command::ShowBalloonIcon {
+        CommandData::ShowBalloonIcon {
             character_id,
             balloon_id,
             wait,
@@ -557,8 +573,8 @@ where
             writer.write_param("wait", wait)?;
             writer.finish()?;
         }
-        Command::ChangePlayerFollowers { is_show } => {
-            let fn_name = if *is_show {
+        CommandData::ChangePlayerFollowers { value } => {
+            let fn_name = if *value == 0 {
                 "show_player_followers"
             } else {
                 "hide_player_followers"
@@ -567,13 +583,13 @@ where
             write_indent(&mut writer, indent)?;
             writeln!(&mut writer, "{fn_name}()")?
         }
-        Command::FadeoutScreen => {
+        CommandData::FadeoutScreen {} => {
             FunctionCallWriter::new(&mut writer, indent, "fadeout_screen")?.finish()?;
         }
-        Command::FadeinScreen => {
+        CommandData::FadeinScreen {} => {
             FunctionCallWriter::new(&mut writer, indent, "fadein_screen")?.finish()?;
         }
-        Command::TintScreen {
+        CommandData::TintScreen {
             tone,
             duration,
             wait,
@@ -586,7 +602,7 @@ where
                 "tint_screen(tone={tone:?}, duration={duration}, wait={wait})"
             )?
         }
-        Command::FlashScreen {
+        CommandData::FlashScreen {
             color,
             duration,
             wait,
@@ -599,7 +615,7 @@ where
                 "flash_screen(color={color:?}, duration={duration}, wait={wait})"
             )?
         }
-        Command::ShakeScreen {
+        CommandData::ShakeScreen {
             power,
             speed,
             duration,
@@ -613,16 +629,17 @@ where
             writer.write_param("wait", wait)?;
             writer.finish()?;
         }
-        Command::Wait { duration } => {
+        CommandData::Wait { duration } => {
             let mut writer = FunctionCallWriter::new(&mut writer, indent, "wait")?;
             writer.set_multiline(false);
             writer.write_param("duration", duration)?;
             writer.finish()?;
         }
-        Command::ShowPicture {
+        CommandData::ShowPicture {
             picture_id,
             picture_name,
             origin,
+            designation,
             x,
             y,
             scale_x,
@@ -631,13 +648,13 @@ where
             blend_mode,
         } => {
             let picture_name = escape_string(picture_name);
-            let x = match x {
-                MaybeRef::Constant(value) => value.to_string(),
-                MaybeRef::Ref(id) => config.get_variable_name(*id),
-            };
-            let y = match y {
-                MaybeRef::Constant(value) => value.to_string(),
-                MaybeRef::Ref(id) => config.get_variable_name(*id),
+            let (x, y) = match designation {
+                0 => (x.to_string(), y.to_string()),
+                1 => (
+                    config.get_variable_name(u32::try_from(*x)?),
+                    config.get_variable_name(u32::try_from(*y)?),
+                ),
+                _ => bail!("{designation} is not a valid picture designation"),
             };
 
             write_indent(&mut writer, indent)?;
@@ -673,95 +690,94 @@ where
             write_indent(&mut writer, indent)?;
             writeln!(&mut writer, ")")?;
         }
-        Command::ErasePicture { picture_id } => {
+        CommandData::ErasePicture { picture_id } => {
             let mut writer = FunctionCallWriter::new(&mut writer, indent, "erase_picture")?;
             writer.set_multiline(false);
             writer.write_param("picture_id", picture_id)?;
             writer.finish()?;
         }
-        Command::PlayBgm { audio } => {
+        CommandData::PlayBgm { audio } => {
             let mut writer = FunctionCallWriter::new(&mut writer, indent, "play_bgm")?;
-            writer.write_param("audio", audio)?;
+            writer.write_param("audio", &audio.audio)?;
             writer.finish()?;
         }
-        Command::FadeoutBgm { duration } => {
+        CommandData::FadeoutBgm { duration } => {
             let mut writer = FunctionCallWriter::new(&mut writer, indent, "fadeout_bgm")?;
             writer.set_multiline(false);
             writer.write_param("duration", duration)?;
             writer.finish()?;
         }
-        Command::SaveBgm => {
+        CommandData::SaveBgm {} => {
             write_indent(&mut writer, indent)?;
             writeln!(&mut writer, "save_bgm()")?;
         }
-        Command::ResumeBgm => {
+        CommandData::ResumeBgm {} => {
             write_indent(&mut writer, indent)?;
             writeln!(&mut writer, "resume_bgm()")?;
         }
-        Command::PlayBgs { audio } => {
+        CommandData::PlayBgs { audio } => {
             write_indent(&mut writer, indent)?;
             writeln!(&mut writer, "play_bgs(")?;
 
             write_indent(&mut writer, indent + 1)?;
             write!(&mut writer, "audio=")?;
-            write_audio_file(&mut writer, indent + 1, audio)?;
+            write_audio_file(&mut writer, indent + 1, &audio.audio)?;
 
             write_indent(&mut writer, indent)?;
             writeln!(&mut writer, ")")?;
         }
-        Command::FadeoutBgs { duration } => {
+        CommandData::FadeoutBgs { duration } => {
             write_indent(&mut writer, indent)?;
             writeln!(&mut writer, "fadeout_bgs(duration={duration})")?;
         }
-        Command::PlaySe { audio } => {
+        CommandData::PlaySe { audio } => {
             let mut writer = FunctionCallWriter::new(&mut writer, indent, "play_se")?;
-            writer.write_param("audio", audio)?;
+            writer.write_param("audio", &audio.audio)?;
             writer.finish()?;
         }
-        Command::GetLocationInfo {
+        CommandData::GetLocationInfo {
             variable_id,
-            kind,
+            info_type,
+            designation,
             x,
             y,
         } => {
             let variable = config.get_variable_name(*variable_id);
-            let x = match x {
-                MaybeRef::Constant(x) => x.to_string(),
-                MaybeRef::Ref(x) => config.get_variable_name(*x),
-            };
-            let y = match y {
-                MaybeRef::Constant(y) => y.to_string(),
-                MaybeRef::Ref(y) => config.get_variable_name(*y),
+            let (x, y) = match designation {
+                0 => (x.to_string(), y.to_string()),
+                1 => (
+                    config.get_variable_name(u32::try_from(*x)?),
+                    config.get_variable_name(u32::try_from(*y)?),
+                ),
+                _ => bail!("location designation {designation} is not supported"),
             };
 
-            let value = match kind {
-                GetLocationInfoKind::TerrainTag => {
-                    format!("game_map.get_terrain_tag(x={x}, y={y})")
-                }
-                GetLocationInfoKind::EventId => {
-                    format!("game_map.get_event_id(x={x}, y={y})")
-                }
+            let value = match info_type {
+                0 => format!("game_map.get_terrain_tag(x={x}, y={y})"),
+                1 => format!("game_map.get_event_id(x={x}, y={y})"),
+                _ => bail!("location info type {info_type} is not supported"),
             };
 
             write_indent(&mut writer, indent)?;
             writeln!(&mut writer, "{variable} = {value}")?;
         }
-        Command::BattleProcessing {
+        CommandData::BattleProcessing {
+            designation,
             troop_id,
             can_escape,
             can_lose,
         } => {
             let mut writer = FunctionCallWriter::new(&mut writer, indent, "battle_processing")?;
-            match troop_id {
-                Some(MaybeRef::Constant(id)) => {
-                    let name = config.get_troop_name(*id);
+            match designation {
+                0 => {
+                    let name = config.get_troop_name(*troop_id);
                     writer.write_param("troop", &Ident(&name))?;
                 }
-                Some(MaybeRef::Ref(id)) => {
-                    let name = config.get_variable_name(*id);
+                1 => {
+                    let name = config.get_variable_name(*troop_id);
                     writer.write_param("troop_id", &Ident(&name))?;
                 }
-                None => {
+                _ => {
                     writer.write_param("troop_id", &Ident("game.random_encounter_troop_id()"))?;
                 }
             }
@@ -769,7 +785,7 @@ where
             writer.write_param("can_lose", can_lose)?;
             writer.finish()?;
         }
-        Command::NameInputProcessing { actor_id, max_len } => {
+        CommandData::NameInputProcessing { actor_id, max_len } => {
             let actor = config.get_actor_name(*actor_id);
 
             let mut writer = FunctionCallWriter::new(&mut writer, indent, "name_input_processing")?;
@@ -778,79 +794,54 @@ where
             writer.write_param("max_len", max_len)?;
             writer.finish()?;
         }
-        Command::ChangeHp {
-            actor_id,
-            is_add,
+        CommandData::ChangeHp {
+            actor,
+            operation,
             value,
             allow_death,
         } => {
+            let (actor_param, actor) = actor2py(config, actor)?;
+            let sign = if *operation == 0 { "" } else { "-" };
+            let value = operand2py(config, value);
+            let value = format!("{sign}{value}");
+
             let mut writer = FunctionCallWriter::new(&mut writer, indent, "gain_hp")?;
             writer.set_multiline(false);
-            match actor_id {
-                MaybeRef::Constant(actor_id) => {
-                    let name = config.get_actor_name(*actor_id);
-                    writer.write_param("actor", &Ident(&name))?;
-                }
-                MaybeRef::Ref(variable_id) => {
-                    let name = config.get_variable_name(*variable_id);
-                    writer.write_param("actor_id", &Ident(&name))?;
-                }
-            };
-            let sign = if *is_add { "" } else { "-" };
-            let value = match value {
-                MaybeRef::Constant(value) => value.to_string(),
-                MaybeRef::Ref(id) => config.get_variable_name(*id),
-            };
-            let value = format!("{sign}{value}");
+            writer.write_param(actor_param, &Ident(&actor))?;
             writer.write_param("value", &Ident(&value))?;
             writer.write_param("allow_death", allow_death)?;
             writer.finish()?;
         }
-        Command::ChangeMp {
-            actor_id,
-            is_add,
+        CommandData::ChangeMp {
+            actor,
+            operation,
             value,
         } => {
-            let mut writer = FunctionCallWriter::new(&mut writer, indent, "gain_mp")?;
-            writer.set_multiline(false);
-            match actor_id {
-                MaybeRef::Constant(actor_id) => {
-                    let name = config.get_actor_name(*actor_id);
-                    writer.write_param("actor", &Ident(&name))?;
-                }
-                MaybeRef::Ref(variable_id) => {
-                    let name = config.get_variable_name(*variable_id);
-                    writer.write_param("actor_id", &Ident(&name))?;
-                }
-            };
-            let sign = if *is_add { "" } else { "-" };
-            let value = match value {
-                MaybeRef::Constant(value) => value.to_string(),
-                MaybeRef::Ref(id) => config.get_variable_name(*id),
-            };
+            let (actor_param, actor) = actor2py(config, actor)?;
+            let sign = if *operation == 0 { "" } else { "-" };
+            let value = operand2py(config, value);
             let value = format!("{sign}{value}");
 
+            let mut writer = FunctionCallWriter::new(&mut writer, indent, "gain_mp")?;
+            writer.set_multiline(false);
+            writer.write_param(actor_param, &Ident(&actor))?;
             writer.write_param("value", &Ident(&value))?;
             writer.finish()?;
         }
-        Command::ChangeState {
-            actor_id,
-            is_add_state,
+        CommandData::ChangeState {
+            actor,
+            operation,
             state_id,
         } => {
-            let actor_arg = match actor_id {
-                MaybeRef::Constant(0) => "actors=game_party".to_string(),
-                MaybeRef::Constant(actor_id) => {
-                    let name = config.get_actor_name(*actor_id);
-                    format!("actor={name}")
-                }
-                MaybeRef::Ref(variable_id) => {
-                    let name = config.get_variable_name(*variable_id);
-                    format!("actor_id={name}")
+            let actor_arg = match actor {
+                Operand::Constant(0) => "actors=game_party".to_string(),
+                actor => {
+                    let (actor_param, actor) = actor2py(config, actor)?;
+                    format!("{actor_param}={actor}")
                 }
             };
 
-            let fn_name = if *is_add_state {
+            let fn_name = if *operation == 0 {
                 "add_state"
             } else {
                 "remove_state"
@@ -860,51 +851,30 @@ where
             write_indent(&mut writer, indent)?;
             writeln!(&mut writer, "{fn_name}({actor_arg}, state={state})")?;
         }
-        Command::ChangeLevel {
-            actor_id,
-            is_add,
+        CommandData::ChangeLevel {
+            actor,
+            operation,
             value,
             show_level_up,
         } => {
-            let actor_arg = match actor_id {
-                MaybeRef::Constant(actor_id) => {
-                    let name = config.get_actor_name(*actor_id);
-                    format!("actor={name}")
-                }
-                MaybeRef::Ref(variable_id) => {
-                    let name = config.get_variable_name(*variable_id);
-                    format!("actor_id={name}")
-                }
-            };
-            let sign = if *is_add { "" } else { "-" };
-            let value = match value {
-                MaybeRef::Constant(value) => value.to_string(),
-                MaybeRef::Ref(id) => config.get_variable_name(*id),
-            };
+            let (actor_param, actor) = actor2py(config, actor)?;
+            let sign = if *operation == 0 { "" } else { "-" };
+            let value = operand2py(config, value);
             let show_level_up = stringify_bool(*show_level_up);
 
             write_indent(&mut writer, indent)?;
             writeln!(
                 &mut writer,
-                "gain_level({actor_arg}, value={sign}{value}, show_level_up={show_level_up})"
+                "gain_level({actor_param}={actor}, value={sign}{value}, show_level_up={show_level_up})"
             )?;
         }
-        Command::ChangeSkill {
-            actor_id,
-            is_learn_skill,
+        CommandData::ChangeSkill {
+            actor,
+            operation,
             skill_id,
         } => {
-            let actor_arg = match actor_id {
-                MaybeRef::Constant(actor_id) => {
-                    let name = config.get_actor_name(*actor_id);
-                    format!("actor={name}")
-                }
-                MaybeRef::Ref(variable_id) => {
-                    let name = config.get_variable_name(*variable_id);
-                    format!("actor_id={name}")
-                }
-            };
-            let fn_name = if *is_learn_skill {
+            let (actor_param, actor) = actor2py(config, actor)?;
+            let fn_name = if *operation == 0 {
                 "learn_skill"
             } else {
                 "forget_skill"
@@ -912,16 +882,19 @@ where
             let skill = config.get_skill_name(*skill_id);
 
             write_indent(&mut writer, indent)?;
-            writeln!(&mut writer, "{fn_name}({actor_arg}, skill={skill})")?;
+            writeln!(
+                &mut writer,
+                "{fn_name}({actor_param}={actor}, skill={skill})"
+            )?;
         }
-        Command::ChangeClass {
+        CommandData::ChangeClass {
             actor_id,
             class_id,
             keep_exp,
         } => {
             let actor = config.get_actor_name(*actor_id);
             let class = config.get_class_name(*class_id);
-            let keep_exp = stringify_bool(*keep_exp);
+            let keep_exp = stringify_bool(keep_exp.unwrap_or(false));
 
             write_indent(&mut writer, indent)?;
             writeln!(
@@ -929,7 +902,7 @@ where
                 "change_class(actor={actor}, klass={class}, keep_exp={keep_exp})"
             )?;
         }
-        Command::ChangeActorImages {
+        CommandData::ChangeActorImages {
             actor_id,
             character_name,
             character_index,
@@ -966,16 +939,16 @@ where
             write_indent(&mut writer, indent)?;
             writeln!(&mut writer, ")")?;
         }
-        Command::ForceAction {
-            is_enemy,
-            id,
+        CommandData::ForceAction {
+            subject_type,
+            subject_id,
             skill_id,
             target_index,
         } => {
-            let arg_0 = if *is_enemy {
-                format!("enemy_index={id}")
+            let arg_0 = if *subject_type == 0 {
+                format!("enemy_index={subject_id}")
             } else {
-                let actor = config.get_actor_name(*id);
+                let actor = config.get_actor_name(*subject_id);
                 format!("actor={actor}")
             };
             let skill = config.get_skill_name(*skill_id);
@@ -986,27 +959,32 @@ where
                 "force_action({arg_0}, skill={skill}, target_index={target_index})"
             )?;
         }
-        Command::AbortBattle => {
+        CommandData::AbortBattle {} => {
             let mut writer = FunctionCallWriter::new(&mut writer, indent, "abort_battle")?;
             writer.finish()?;
         }
-        Command::GameOver => {
+        CommandData::GameOver {} => {
             let mut writer = FunctionCallWriter::new(&mut writer, indent, "game_over")?;
             writer.finish()?;
         }
-        Command::ReturnToTitleScreen => {
+        CommandData::ReturnToTitleScreen {} => {
             let mut writer =
                 FunctionCallWriter::new(&mut writer, indent, "return_to_title_screen")?;
             writer.finish()?;
         }
-        Command::Script { lines } => {
+        CommandData::Script { line } => {
+            let lines = take_lines(commands, |data| match data {
+                CommandData::ScriptExtra { line } => Some(line),
+                _ => None,
+            });
+
             write_indent(&mut writer, indent)?;
             writeln!(&mut writer, "script(")?;
 
             write_indent(&mut writer, indent + 1)?;
             writeln!(writer, "lines=[")?;
 
-            for line in lines {
+            for line in std::iter::once(line).chain(lines.iter()) {
                 let line = escape_string(line);
 
                 write_indent(&mut writer, indent + 2)?;
@@ -1019,10 +997,10 @@ where
             write_indent(&mut writer, indent)?;
             writeln!(&mut writer, ")")?;
         }
-        Command::PluginCommand { params } => {
+        CommandData::PluginCommand { command } => {
             write_indent(&mut writer, indent)?;
             write!(&mut writer, "plugin_command(")?;
-            for (i, param) in params.iter().enumerate() {
+            for (i, param) in command.split(' ').enumerate() {
                 if i != 0 {
                     write!(&mut writer, ", ")?;
                 }
@@ -1031,7 +1009,7 @@ where
             }
             writeln!(&mut writer, ")")?;
         }
-        Command::When {
+        CommandData::When {
             choice_index,
             choice_name,
         } => {
@@ -1041,7 +1019,7 @@ where
                 "if get_choice_index() == {choice_index}: # {choice_name}"
             )?;
         }
-        Command::WhenCancel {
+        CommandData::WhenCancel {
             choice_index,
             choice_name,
         } => {
@@ -1053,42 +1031,128 @@ where
                 "if get_choice_index() == -1: # Cancel, index={choice_index}"
             )?;
         }
-        Command::WhenEnd => {
+        CommandData::WhenEnd {} => {
             // Trust indents over end commands
         }
-        Command::Else => {
+        CommandData::Else {} => {
             write_indent(&mut writer, indent)?;
             writeln!(&mut writer, "else:")?;
         }
-        Command::ConditionalBranchEnd => {
+        CommandData::ConditionalBranchEnd {} => {
             // Trust indents over end commands
         }
-        Command::RepeatAbove => {
+        CommandData::RepeatAbove {} => {
             // This is just a loop end
         }
-        Command::IfWin => {
+        CommandData::IfWin {} => {
             writeln!(&mut writer, "if game_battle_result.is_win():")?;
         }
-        Command::IfEscape => {
+        CommandData::IfEscape {} => {
             writeln!(&mut writer, "if game_battle_result.is_escape():")?;
         }
-        Command::IfLose => {
+        CommandData::IfLose {} => {
             writeln!(&mut writer, "if game_battle_result.is_lose():")?;
         }
-        Command::BattleResultEnd => {
+        CommandData::BattleResultEnd {} => {
             // Trust indents over end commands
         }
-        Command::Unknown { code, parameters } => {
+        data => {
+            let code = data.code();
+            let parameters = data.clone().into_parameters();
+
             write_indent(&mut writer, indent)?;
             writeln!(
                 &mut writer,
-                "# Unknown Command Code {code:?}, parameters: {parameters:?}"
+                "# Unknown Command Code {code}, parameters: {parameters:?}"
             )?;
         }
     }
     Ok(())
 }
 
+/// Take the lines of the commands that continue the last command.
+fn take_lines<'a, F>(commands: &mut Peekable<Iter<'a, TypedCommand>>, get_line: F) -> Vec<String>
+where
+    F: Fn(&CommandData) -> Option<&String>,
+{
+    let mut lines = Vec::new();
+    while let Some(command) = commands.next_if(|command| get_line(&command.data).is_some()) {
+        lines.extend(get_line(&command.data).cloned());
+    }
+    lines
+}
+
+/// Get the python for an operand.
+fn operand2py(config: &Config, operand: &Operand) -> String {
+    match operand {
+        Operand::Constant(value) => value.to_string(),
+        Operand::Variable(id) => config.get_variable_name(*id),
+    }
+}
+
+/// Get the parameter name and python for an actor operand.
+fn actor2py(config: &Config, actor: &Operand) -> anyhow::Result<(&'static str, String)> {
+    match actor {
+        Operand::Constant(actor_id) => {
+            let actor_id = u32::try_from(*actor_id).context("`actor_id` is not a `u32`")?;
+            Ok(("actor", config.get_actor_name(actor_id)))
+        }
+        Operand::Variable(variable_id) => Ok(("actor_id", config.get_variable_name(*variable_id))),
+    }
+}
+
+/// Get the python for a game data operand of a control variables command.
+fn game_data2py(config: &Config, kind: u32, param1: i32, param2: i32) -> anyhow::Result<String> {
+    let value = match (kind, param2) {
+        // Item
+        (0, _) => {
+            let item_id = u32::try_from(param1).context("`item_id` is not a `u32`")?;
+            let name = config.get_item_name(item_id);
+
+            format!("game_party.get_num_items(item={name})")
+        }
+        // Actor
+        (3, check) => {
+            let actor_id = u32::try_from(param1).context("`actor_id` is not a `u32`")?;
+            let name = config.get_actor_name(actor_id);
+
+            match check {
+                0 => format!("{name}.level"),
+                1 => format!("{name}.currentExp"),
+                2 => format!("{name}.hp"),
+                3 => format!("{name}.mp"),
+                4..=11 => {
+                    let param_index = check - 4;
+                    format!("{name}.param({param_index})")
+                }
+                _ => bail!("{check} is not a valid actor game data check"),
+            }
+        }
+        // Enemy
+        (4, 2..=9) => {
+            let enemy_index = u32::try_from(param1).context("`enemy_index` is not a `u32`")?;
+            let param_index = param2 - 2;
+
+            format!("game_troop.members[{enemy_index}].param({param_index})")
+        }
+        // Character
+        (5, 0) => format!("game.get_character(id={param1}).map_x"),
+        (5, 1) => format!("game.get_character(id={param1}).map_y"),
+        (5, 3) => format!("game.get_character(id={param1}).screen_x"),
+        (5, 4) => format!("game.get_character(id={param1}).screen_y"),
+        // Other
+        (7, _) => match param1 {
+            0 => "game_map.map_id()".to_string(),
+            2 => "game_party.gold".to_string(),
+            3 => "game_party.steps".to_string(),
+            _ => bail!("other game data check {param1} is not supported"),
+        },
+        _ => bail!("game data operand {kind} with {param1}, {param2} is not supported"),
+    };
+
+    Ok(value)
+}
+
 fn stringify_bool(b: bool) -> &'static str {
     match b {
         true => "True",
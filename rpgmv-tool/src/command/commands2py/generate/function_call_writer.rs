@@ -180,6 +180,7 @@ impl FunctionParamValue for rpgmv_types::MoveRoute {
         for command in self.list.iter() {
             let command_indent = command
                 .indent
+                .flatten()
                 .map(|indent| indent.to_string())
                 .unwrap_or_else(|| "None".to_string());
 
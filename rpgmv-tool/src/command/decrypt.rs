@@ -1,32 +1,144 @@
+use crate::util::get_encryption_key;
+use crate::util::get_game_assets_dir;
+use crate::util::is_stdio;
+use crate::util::list_files;
 use crate::util::try_metadata;
+use crate::util::try_read_json;
 use anyhow::Context;
 use anyhow::anyhow;
 use anyhow::bail;
 use anyhow::ensure;
 use clap::Parser;
 use glob::glob;
+use rayon::iter::IntoParallelRefIterator;
+use rayon::iter::ParallelIterator;
+use std::collections::BTreeMap;
 use std::fs::File;
+use std::io::BufRead;
 use std::io::BufReader;
+use std::io::IsTerminal;
 use std::io::Write;
+use std::num::NonZeroUsize;
 use std::path::Path;
 use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 
 #[derive(Debug, Parser)]
 #[command(about = "Decrypt a file")]
 pub struct Options {
-    #[arg(long = "input", short = 'i', help = "A file to decrypt")]
+    #[arg(
+        long = "input",
+        short = 'i',
+        help = "A file to decrypt. Use \"-\" for stdin",
+        conflicts_with = "game"
+    )]
     pub input: Vec<PathBuf>,
 
-    #[arg(long = "glob-input", help = "A glob of input files to decrypt")]
+    #[arg(
+        long = "glob-input",
+        help = "A glob of input files to decrypt",
+        conflicts_with = "game"
+    )]
     pub glob_input: Vec<String>,
 
-    #[arg(long = "output", short = 'o', help = "The output folder")]
+    #[arg(
+        long = "game",
+        help = "The path to a game. Every encrypted file in it will be decrypted, keeping the directory structure"
+    )]
+    pub game: Option<PathBuf>,
+
+    #[arg(
+        long = "output",
+        short = 'o',
+        help = "The output folder or file. Use \"-\" for stdout"
+    )]
     pub output: PathBuf,
+
+    #[arg(
+        long = "key",
+        short = 'k',
+        help = "The key for RPGMaker files, as hex. If not provided, it will be recovered from each file"
+    )]
+    pub key: Option<rpgmvp::Key>,
+
+    #[arg(
+        long = "write-system-json",
+        help = "In game mode, write a copy of System.json that marks the game as unencrypted",
+        requires = "game"
+    )]
+    pub write_system_json: bool,
+
+    #[arg(
+        long = "jobs",
+        short = 'j',
+        help = "The number of files to decrypt at once. Defaults to the number of cpus"
+    )]
+    pub jobs: Option<NonZeroUsize>,
+
+    #[arg(
+        long = "keep-going",
+        help = "Keep decrypting other files if one fails, instead of stopping"
+    )]
+    pub keep_going: bool,
+
+    #[arg(
+        long = "report",
+        help = "Write a JSON report of every decrypted file to the given path"
+    )]
+    pub report: Option<PathBuf>,
+}
+
+/// Options for decrypting many files.
+#[derive(Debug)]
+struct BatchOptions {
+    jobs: usize,
+    keep_going: bool,
+    report: Option<PathBuf>,
+}
+
+/// A report on decrypting many files.
+#[derive(Debug, serde::Serialize)]
+struct Report {
+    ok: usize,
+    failed: usize,
+    keys: Vec<String>,
+    files: Vec<ReportEntry>,
+}
+
+/// A report on decrypting a single file.
+#[derive(Debug, serde::Serialize)]
+struct ReportEntry {
+    input: PathBuf,
+    output: PathBuf,
+    key: Option<String>,
+    error: Option<String>,
 }
 
 /// Interface inspired by mv.
 /// See: https://man7.org/linux/man-pages/man1/mv.1p.html
 pub fn exec(options: Options) -> anyhow::Result<()> {
+    let jobs = match options.jobs {
+        Some(jobs) => jobs,
+        None => std::thread::available_parallelism()?,
+    };
+    let batch_options = BatchOptions {
+        jobs: jobs.get(),
+        keep_going: options.keep_going,
+        report: options.report,
+    };
+
+    if let Some(game) = options.game.as_deref() {
+        return exec_game(
+            game,
+            &options.output,
+            options.key,
+            options.write_system_json,
+            &batch_options,
+        );
+    }
+
     let mut inputs = options.input;
     for input in options.glob_input {
         let iter = glob(&input)?;
@@ -39,13 +151,24 @@ pub fn exec(options: Options) -> anyhow::Result<()> {
 
     ensure!(!inputs.is_empty(), "need at least 1 input");
 
+    let key = options.key;
+
+    // Streams can only be used one at a time.
+    if is_stdio(&options.output) || inputs.iter().any(|input| is_stdio(input)) {
+        ensure!(
+            inputs.len() == 1,
+            "stdin and stdout can only be used with a single input"
+        );
+        return exec_scalar(&inputs[0], &options.output, key);
+    }
+
     let output_metadata = try_metadata(&options.output)
         .with_context(|| format!("failed to stat \"{}\"", options.output.display()))?;
 
     // If the output is a directory, use the vector impl.
     match output_metadata {
         Some(metadata) if metadata.is_dir() => {
-            return exec_vector(&inputs, &options.output);
+            return exec_vector(&inputs, &options.output, key, &batch_options);
         }
         Some(_) | None => {}
     }
@@ -55,7 +178,7 @@ pub fn exec(options: Options) -> anyhow::Result<()> {
 
         // For file destinations or non-existent destinations
         // We filter out directory outputs earlier.
-        exec_scalar(input, &options.output)
+        exec_scalar(input, &options.output, key)
     } else {
         // We can't use the scalar impl since there must be more than 1 input.
         // We can't use the vector impl since the target output is either a file or does not exist.
@@ -67,11 +190,22 @@ pub fn exec(options: Options) -> anyhow::Result<()> {
     }
 }
 
-fn exec_scalar(input: &Path, output: &Path) -> anyhow::Result<()> {
-    decrypt_single_file(input, output)
+fn exec_scalar(input: &Path, output: &Path, key: Option<rpgmvp::Key>) -> anyhow::Result<()> {
+    let key = decrypt_single_file(input, output, key)?;
+    if let Some(key) = key {
+        eprintln!("Key for \"{}\": {key}", input.display());
+    }
+
+    Ok(())
 }
 
-fn exec_vector(inputs: &[PathBuf], output: &Path) -> anyhow::Result<()> {
+fn exec_vector(
+    inputs: &[PathBuf],
+    output: &Path,
+    key: Option<rpgmvp::Key>,
+    batch_options: &BatchOptions,
+) -> anyhow::Result<()> {
+    let mut files = Vec::with_capacity(inputs.len());
     for input in inputs.iter() {
         let input_file_name = input
             .file_name()
@@ -79,36 +213,247 @@ fn exec_vector(inputs: &[PathBuf], output: &Path) -> anyhow::Result<()> {
 
         let output = {
             let mut path = output.join(input_file_name);
-            path.set_extension("png");
+            path.set_extension(get_file_kind(input).extension());
             path
         };
 
-        decrypt_single_file(input, &output)?;
+        files.push((input.clone(), output));
     }
 
-    Ok(())
+    decrypt_batch(&files, key, batch_options)
 }
 
-fn decrypt_single_file(input: &Path, output: &Path) -> anyhow::Result<()> {
-    let output_metadata =
-        try_metadata(output).with_context(|| format!("failed to stat \"{}\"", output.display()))?;
+fn exec_game(
+    game: &Path,
+    output: &Path,
+    key: Option<rpgmvp::Key>,
+    write_system_json: bool,
+    batch_options: &BatchOptions,
+) -> anyhow::Result<()> {
+    let root = get_game_assets_dir(game)?;
+    let system_path = root.join("data").join("System.json");
+    let system = try_read_json(&system_path)?;
 
-    if output_metadata.is_some() {
-        bail!(
-            "output path \"{}\" exists, refusing to overwrite",
-            output.display()
-        );
+    // Prefer the key from System.json over recovering it,
+    // since not every file kind can have its key recovered.
+    let key = match key {
+        Some(key) => Some(key),
+        None => system
+            .as_ref()
+            .map(get_encryption_key)
+            .transpose()?
+            .flatten(),
+    };
+
+    let inputs: Vec<_> = list_files(&root)?
+        .into_iter()
+        .filter(|path| {
+            path.extension()
+                .and_then(|extension| extension.to_str())
+                .and_then(rpgmvp::FileKind::from_extension)
+                .is_some()
+        })
+        .collect();
+
+    if write_system_json {
+        let mut system =
+            system.with_context(|| format!("missing \"{}\"", system_path.display()))?;
+        let system_object = system
+            .as_object_mut()
+            .context("System.json is not an object")?;
+        system_object.insert("hasEncryptedImages".into(), false.into());
+        system_object.insert("hasEncryptedAudio".into(), false.into());
+
+        let output = output.join("data").join("System.json");
+        if let Some(parent) = output.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create dir \"{}\"", parent.display()))?;
+        }
+        let system = serde_json::to_string(&system)?;
+        std::fs::write(&output, system)
+            .with_context(|| format!("failed to write \"{}\"", output.display()))?;
     }
 
-    let file =
-        File::open(input).with_context(|| format!("failed to open \"{}\"", input.display()))?;
+    let mut files = Vec::with_capacity(inputs.len());
+    for input in inputs.iter() {
+        let relative_path = input.strip_prefix(&root)?;
+        let output = {
+            let mut path = output.join(relative_path);
+            path.set_extension(get_file_kind(input).extension());
+            path
+        };
+
+        files.push((input.clone(), output));
+    }
+
+    decrypt_batch(&files, key, batch_options)
+}
 
-    let file = BufReader::new(file);
-    let mut reader = rpgmvp::Reader::new(file);
-    reader.read_header().context("invalid header")?;
-    let key = reader.extract_key().context("failed to extract key")?;
-    let key_hex = base16ct::lower::encode_string(&key);
-    println!("Key for \"{}\": {}", input.display(), key_hex);
+/// Decrypt many files at once, printing a summary at the end.
+///
+/// Each file is a pair of input and output paths.
+fn decrypt_batch(
+    files: &[(PathBuf, PathBuf)],
+    key: Option<rpgmvp::Key>,
+    batch_options: &BatchOptions,
+) -> anyhow::Result<()> {
+    let thread_pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(batch_options.jobs)
+        .build()?;
+
+    let show_progress = std::io::stderr().is_terminal();
+    let done = AtomicUsize::new(0);
+    let stop = AtomicBool::new(false);
+    let results: Vec<Option<anyhow::Result<Option<rpgmvp::Key>>>> = thread_pool.install(|| {
+        files
+            .par_iter()
+            .map(|(input, output)| {
+                if stop.load(Ordering::Relaxed) {
+                    return None;
+                }
+
+                let result = (|| {
+                    if let Some(parent) = output.parent() {
+                        std::fs::create_dir_all(parent).with_context(|| {
+                            format!("failed to create dir \"{}\"", parent.display())
+                        })?;
+                    }
+
+                    decrypt_single_file(input, output, key)
+                })();
+                if result.is_err() && !batch_options.keep_going {
+                    stop.store(true, Ordering::Relaxed);
+                }
+
+                let done = done.fetch_add(1, Ordering::Relaxed) + 1;
+                if show_progress {
+                    eprint!("\rDecrypted {done}/{}", files.len());
+                }
+
+                Some(result)
+            })
+            .collect()
+    });
+    if show_progress {
+        eprintln!();
+    }
+
+    let mut report = Report {
+        ok: 0,
+        failed: 0,
+        keys: Vec::new(),
+        files: Vec::with_capacity(files.len()),
+    };
+    let mut key_counts = BTreeMap::new();
+    let mut first_error = None;
+    for ((input, output), result) in files.iter().zip(results) {
+        let (key, error) = match result {
+            Some(Ok(key)) => {
+                report.ok += 1;
+
+                let key = key.map(|key| key.to_string());
+                if let Some(key) = key.as_ref() {
+                    *key_counts.entry(key.clone()).or_insert(0) += 1;
+                }
+
+                (key, None)
+            }
+            Some(Err(error)) => {
+                report.failed += 1;
+
+                let error_string = format!("{error:#}");
+                eprintln!("Failed to decrypt \"{}\": {error_string}", input.display());
+                first_error.get_or_insert(error);
+
+                (None, Some(error_string))
+            }
+            None => continue,
+        };
+
+        report.files.push(ReportEntry {
+            input: input.clone(),
+            output: output.clone(),
+            key,
+            error,
+        });
+    }
+    report.keys = key_counts.keys().cloned().collect();
+
+    println!("Decrypted {} file(s), {} failed", report.ok, report.failed);
+    for (key, count) in key_counts.iter() {
+        println!("  Key {key}: {count} file(s)");
+    }
+
+    if let Some(report_path) = batch_options.report.as_deref() {
+        let report_string = serde_json::to_string_pretty(&report)?;
+        std::fs::write(report_path, report_string)
+            .with_context(|| format!("failed to write \"{}\"", report_path.display()))?;
+    }
+
+    match first_error {
+        Some(error) if !batch_options.keep_going => Err(error),
+        Some(_) => bail!("failed to decrypt {} file(s)", report.failed),
+        None => Ok(()),
+    }
+}
+
+/// Get the kind of an encrypted file from its extension.
+///
+/// Assume pngs for unknown extensions, as that is the most common asset type.
+fn get_file_kind(input: &Path) -> rpgmvp::FileKind {
+    input
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .and_then(rpgmvp::FileKind::from_extension)
+        .unwrap_or(rpgmvp::FileKind::Png)
+}
+
+/// Decrypt a file of any asset format.
+///
+/// Returns the key for RPGMaker files.
+fn decrypt_single_file(
+    input: &Path,
+    output: &Path,
+    key: Option<rpgmvp::Key>,
+) -> anyhow::Result<Option<rpgmvp::Key>> {
+    if !is_stdio(output) {
+        let output_metadata = try_metadata(output)
+            .with_context(|| format!("failed to stat \"{}\"", output.display()))?;
+
+        if output_metadata.is_some() {
+            bail!(
+                "output path \"{}\" exists, refusing to overwrite",
+                output.display()
+            );
+        }
+    }
+
+    let file: Box<dyn BufRead> = if is_stdio(input) {
+        Box::new(std::io::stdin().lock())
+    } else {
+        let file =
+            File::open(input).with_context(|| format!("failed to open \"{}\"", input.display()))?;
+        Box::new(BufReader::new(file))
+    };
+
+    let mut reader = rpgmv_asset::Reader::new(file)
+        .with_context(|| format!("failed to detect the format of \"{}\"", input.display()))?;
+    if let Some(key) = key {
+        reader.set_rpgmaker_key(key)?;
+    }
+
+    let kind = get_file_kind(input);
+    let key = reader
+        .verify_rpgmaker_key(kind)
+        .with_context(|| format!("failed to verify key for \"{}\"", input.display()))?;
+
+    if is_stdio(output) {
+        let mut writer = std::io::stdout().lock();
+        std::io::copy(&mut reader, &mut writer)?;
+        writer.flush()?;
+
+        return Ok(key);
+    }
 
     let output_tmp = output.with_added_extension("tmp");
     let mut writer = File::create(&output_tmp)
@@ -118,5 +463,5 @@ fn decrypt_single_file(input: &Path, output: &Path) -> anyhow::Result<()> {
     writer.sync_all()?;
     std::fs::rename(&output_tmp, output)?;
 
-    Ok(())
+    Ok(key)
 }
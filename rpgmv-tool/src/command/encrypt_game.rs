@@ -0,0 +1,210 @@
+use crate::util::get_encryption_key;
+use crate::util::get_game_assets_dir;
+use crate::util::list_files;
+use crate::util::try_read_json;
+use anyhow::Context;
+use clap::Parser;
+use std::fs::File;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// The dirs that hold encryptable assets, relative to the assets dir.
+const ASSET_DIRS: &[&str] = &["img", "audio"];
+
+/// The assets that MV always loads unencrypted, relative to the assets dir.
+///
+/// This matches `Decrypter._ignoreList` in `rpg_core.js`.
+const MV_IGNORE_LIST: &[&str] = &["img/system/Window.png"];
+
+/// The engine a game was made with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Engine {
+    Mv,
+    Mz,
+}
+
+impl Engine {
+    /// Detect the engine from the core script of a game.
+    ///
+    /// Returns `None` if neither core script exists.
+    fn detect(root: &Path) -> anyhow::Result<Option<Self>> {
+        for (engine, core_script) in [(Self::Mz, "rmmz_core.js"), (Self::Mv, "rpg_core.js")] {
+            let path = root.join("js").join(core_script);
+            let exists = path
+                .try_exists()
+                .with_context(|| format!("failed to check if \"{}\" exists", path.display()))?;
+            if exists {
+                return Ok(Some(engine));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Get the extension of an encrypted file kind.
+    fn extension(self, kind: rpgmvp::FileKind) -> &'static str {
+        match self {
+            Self::Mv => kind.mv_extension(),
+            Self::Mz => kind.mz_extension(),
+        }
+    }
+
+    /// Check if this engine loads an asset unencrypted, even in encrypted games.
+    ///
+    /// The path is relative to the assets dir.
+    fn ignores(self, relative_path: &Path) -> bool {
+        match self {
+            Self::Mv => MV_IGNORE_LIST
+                .iter()
+                .any(|ignored| relative_path == Path::new(ignored)),
+            Self::Mz => false,
+        }
+    }
+}
+
+#[derive(Debug, Parser)]
+#[command(about = "Encrypt the images and audio of a game")]
+pub struct Options {
+    #[arg(help = "The path to the game to encrypt", default_value = ".")]
+    pub input: PathBuf,
+
+    #[arg(
+        long = "key",
+        short = 'k',
+        help = "The key, as hex. If not provided, the key from System.json is used, or a new one is generated"
+    )]
+    pub key: Option<rpgmvp::Key>,
+
+    #[arg(
+        long = "engine",
+        help = "The engine the game was made with. If not provided, it is detected from the game's core script"
+    )]
+    pub engine: Option<Engine>,
+
+    #[arg(
+        long = "delete-plaintext",
+        help = "Delete the unencrypted files after encrypting them"
+    )]
+    pub delete_plaintext: bool,
+}
+
+pub fn exec(options: Options) -> anyhow::Result<()> {
+    let root = get_game_assets_dir(&options.input)?;
+    let engine = match options.engine {
+        Some(engine) => engine,
+        None => Engine::detect(&root)?
+            .context("failed to detect whether this is an MV or MZ game, use --engine to choose")?,
+    };
+    let system_path = root.join("data").join("System.json");
+    let mut system = try_read_json(&system_path)?
+        .with_context(|| format!("missing \"{}\"", system_path.display()))?;
+    let system_key = get_encryption_key(&system)?;
+    let system_object = system
+        .as_object_mut()
+        .context("System.json is not an object")?;
+
+    let key = match options.key.or(system_key) {
+        Some(key) => key,
+        None => {
+            let mut key = [0; rpgmvp::KEY_LEN];
+            getrandom::fill(&mut key)
+                .map_err(|error| anyhow::anyhow!("failed to generate key: {error}"))?;
+            rpgmvp::Key::new(key)
+        }
+    };
+    println!("Key: {key}");
+
+    for dir in ASSET_DIRS {
+        let dir = root.join(dir);
+        let exists = dir
+            .try_exists()
+            .with_context(|| format!("failed to check if \"{}\" exists", dir.display()))?;
+        if !exists {
+            continue;
+        }
+
+        for input in list_files(&dir)? {
+            let kind = match input
+                .extension()
+                .and_then(|extension| extension.to_str())
+                .and_then(rpgmvp::FileKind::from_unencrypted_extension)
+            {
+                Some(kind) => kind,
+                None => continue,
+            };
+            if engine.ignores(input.strip_prefix(&root)?) {
+                continue;
+            }
+            let output = input.with_extension(engine.extension(kind));
+
+            encrypt_single_file(&input, &output, key)?;
+
+            if options.delete_plaintext {
+                std::fs::remove_file(&input)
+                    .with_context(|| format!("failed to delete \"{}\"", input.display()))?;
+            }
+        }
+    }
+
+    system_object.insert("encryptionKey".into(), key.to_string().into());
+    system_object.insert("hasEncryptedImages".into(), true.into());
+    system_object.insert("hasEncryptedAudio".into(), true.into());
+
+    let system_tmp_path = system_path.with_added_extension("tmp");
+    let system = serde_json::to_string(&system)?;
+    std::fs::write(&system_tmp_path, system)
+        .with_context(|| format!("failed to write \"{}\"", system_tmp_path.display()))?;
+    std::fs::rename(&system_tmp_path, &system_path)?;
+
+    Ok(())
+}
+
+/// Encrypt a file.
+///
+/// This will overwrite the output if it exists,
+/// as it is likely an outdated version of the input.
+fn encrypt_single_file(input: &Path, output: &Path, key: rpgmvp::Key) -> anyhow::Result<()> {
+    let input_file = File::open(input)
+        .with_context(|| format!("failed to open \"{}\" for reading", input.display()))?;
+    let mut input_file = rpgmv_asset::Reader::new(BufReader::new(input_file))
+        .with_context(|| format!("failed to detect the format of \"{}\"", input.display()))?;
+
+    let output_tmp = output.with_added_extension("tmp");
+    let output_file = File::create(&output_tmp)
+        .with_context(|| format!("failed to open \"{}\" for writing", output_tmp.display()))?;
+    let mut output_file = BufWriter::new(output_file);
+
+    let mut writer = rpgmvp::Writer::new(&mut output_file, key.into_bytes());
+    writer.write_header()?;
+    std::io::copy(&mut input_file, &mut writer)?;
+
+    output_file.flush().context("failed to flush")?;
+    let output_file = output_file.into_inner()?;
+    output_file.sync_all()?;
+    std::fs::rename(&output_tmp, output)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn engine_ignores() {
+        let window = Path::new("img").join("system").join("Window.png");
+        assert!(Engine::Mv.ignores(&window));
+        assert!(!Engine::Mz.ignores(&window));
+
+        for path in [
+            "img/system/Window2.png",
+            "img/pictures/Window.png",
+            "audio/se/Window.ogg",
+        ] {
+            assert!(!Engine::Mv.ignores(Path::new(path)));
+        }
+    }
+}
@@ -1,6 +1,8 @@
+use crate::util::is_stdio;
 use anyhow::Context;
 use clap::Parser;
 use std::fs::File;
+use std::io::BufRead;
 use std::io::BufReader;
 use std::io::BufWriter;
 use std::io::Write;
@@ -9,40 +11,81 @@ use std::path::PathBuf;
 #[derive(Debug, Parser)]
 #[command(about = "Encrypt a png")]
 pub struct Options {
-    #[arg(long = "input", short = 'i', help = "A png to encrypt")]
+    #[arg(
+        long = "input",
+        short = 'i',
+        help = "A png to encrypt. Encrypted pngs are decrypted first. Use \"-\" for stdin"
+    )]
     pub input: PathBuf,
 
-    #[arg(long = "output", short = 'o', help = "The output file")]
+    #[arg(
+        long = "output",
+        short = 'o',
+        help = "The output file. Use \"-\" for stdout"
+    )]
     pub output: PathBuf,
 
-    #[arg(long = "key", short = 'k', help = "The key, as hex")]
-    pub key: String,
+    #[arg(
+        long = "key",
+        short = 'k',
+        help = "The key, as hex. If not provided, a new one is generated"
+    )]
+    pub key: Option<rpgmvp::Key>,
 }
 
 pub fn exec(options: Options) -> anyhow::Result<()> {
-    let input = File::open(&options.input)
-        .with_context(|| format!("failed to open \"{}\" for reading", options.input.display()))?;
-    let mut input = BufReader::new(input);
-
-    let output = File::create(&options.output).with_context(|| {
+    let input: Box<dyn BufRead> = if is_stdio(&options.input) {
+        Box::new(std::io::stdin().lock())
+    } else {
+        let input = File::open(&options.input).with_context(|| {
+            format!("failed to open \"{}\" for reading", options.input.display())
+        })?;
+        Box::new(BufReader::new(input))
+    };
+    let mut input = rpgmv_asset::Reader::new(input).with_context(|| {
         format!(
-            "failed to open \"{}\" for writing",
-            options.output.display()
+            "failed to detect the format of \"{}\"",
+            options.input.display()
         )
     })?;
-    let mut output = BufWriter::new(output);
 
-    let mut key = [0; 16];
-    base16ct::mixed::decode(&options.key, &mut key)
-        .with_context(|| format!("failed to decode hex \"{}\"", options.key))?;
+    let key = match options.key {
+        Some(key) => key,
+        None => {
+            let mut key = [0; rpgmvp::KEY_LEN];
+            getrandom::fill(&mut key)
+                .map_err(|error| anyhow::anyhow!("failed to generate key: {error}"))?;
+            let key = rpgmvp::Key::new(key);
+
+            // Log to stderr, so it does not mix with the output.
+            eprintln!("Key: {key}");
+
+            key
+        }
+    };
+
+    // Keep a handle to output files, so they can be synced at the end.
+    let (mut output, output_file): (Box<dyn Write>, _) = if is_stdio(&options.output) {
+        (Box::new(std::io::stdout().lock()), None)
+    } else {
+        let output = File::create(&options.output).with_context(|| {
+            format!(
+                "failed to open \"{}\" for writing",
+                options.output.display()
+            )
+        })?;
+        let output_file = output.try_clone()?;
+        (Box::new(BufWriter::new(output)), Some(output_file))
+    };
 
-    let mut writer = rpgmvp::Writer::new(&mut output, key);
+    let mut writer = rpgmvp::Writer::new(&mut output, key.into_bytes());
     writer.write_header()?;
     std::io::copy(&mut input, &mut writer)?;
 
     output.flush().context("failed to flush")?;
-    let output = output.into_inner()?;
-    output.sync_all()?;
+    if let Some(output_file) = output_file {
+        output_file.sync_all()?;
+    }
 
     Ok(())
 }
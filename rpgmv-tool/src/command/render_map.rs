@@ -0,0 +1,271 @@
+mod autotile;
+mod tilemap;
+
+use self::tilemap::Tilemap;
+use self::tilemap::blit;
+use crate::util::get_encryption_key;
+use crate::util::get_game_assets_dir;
+use crate::util::try_read_json;
+use anyhow::Context;
+use clap::Parser;
+use image::RgbaImage;
+use rpgmv_types::Event;
+use rpgmv_types::EventPage;
+use rpgmv_types::Map;
+use rpgmv_types::TileId;
+use rpgmv_types::Tileset;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::io::Read;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// The default tile size, used by MV games.
+const DEFAULT_TILE_SIZE: u32 = 48;
+
+#[derive(Debug, Parser)]
+#[command(about = "Render a map to a png")]
+pub struct Options {
+    #[arg(long = "game", help = "The path to a game")]
+    pub game: PathBuf,
+
+    #[arg(long = "map", help = "The id of the map to render")]
+    pub map: u16,
+
+    #[arg(long = "output", short = 'o', help = "The output png")]
+    pub output: PathBuf,
+
+    #[arg(
+        long = "key",
+        short = 'k',
+        help = "The key for encrypted images, as hex. If not provided, the key from System.json is used"
+    )]
+    pub key: Option<rpgmvp::Key>,
+
+    #[arg(long = "no-events", help = "Do not draw event graphics")]
+    pub no_events: bool,
+}
+
+pub fn exec(options: Options) -> anyhow::Result<()> {
+    let root = get_game_assets_dir(&options.game)?;
+    let data_dir = root.join("data");
+
+    let system = try_read_json(&data_dir.join("System.json"))?;
+    let key = match options.key {
+        Some(key) => Some(key),
+        None => system
+            .as_ref()
+            .map(get_encryption_key)
+            .transpose()?
+            .flatten(),
+    };
+    let tile_size = system
+        .as_ref()
+        .and_then(|system| system.get("tileSize"))
+        .and_then(|tile_size| tile_size.as_u64())
+        .map(u32::try_from)
+        .transpose()
+        .context("invalid tile size in System.json")?
+        .unwrap_or(DEFAULT_TILE_SIZE);
+
+    let map: Map = read_json(&data_dir.join(format!("Map{:03}.json", options.map)))?;
+    let tilesets: Vec<Option<Tileset>> = read_json(&data_dir.join("Tilesets.json"))?;
+    let tileset = usize::try_from(map.tileset_id)
+        .ok()
+        .and_then(|tileset_id| tilesets.get(tileset_id))
+        .and_then(|tileset| tileset.as_ref())
+        .with_context(|| format!("missing tileset {}", map.tileset_id))?;
+
+    let mut image_loader = ImageLoader::new(root, key);
+    let sheets = tileset
+        .tileset_names
+        .iter()
+        .map(|name| image_loader.load("tilesets", name))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let tilemap = Tilemap::new(&map, tileset, &sheets, tile_size, tile_size);
+    let mut image = tilemap.new_image();
+    tilemap.draw_lower(&mut image);
+
+    // Events are drawn by priority, and then by position like the game does.
+    let mut pages: Vec<(&Event, &EventPage)> = Vec::new();
+    if !options.no_events {
+        for event in map.events.iter().flatten() {
+            if let Some(page) = event.pages.first() {
+                pages.push((event, page));
+            }
+        }
+    }
+    pages.sort_by_key(|(event, page)| (page.priority_type, event.y, event.id));
+
+    let mut drew_upper = false;
+    for (event, page) in pages {
+        // Characters above other characters are drawn after upper tiles.
+        if page.priority_type > 1 && !drew_upper {
+            tilemap.draw_upper(&mut image);
+            drew_upper = true;
+        }
+
+        // A broken event graphic should not stop the rest of the map from being rendered.
+        let result = draw_event(
+            &mut image,
+            &tilemap,
+            &mut image_loader,
+            page,
+            (u32::from(event.x), u32::from(event.y)),
+            tile_size,
+        );
+        if let Err(error) = result {
+            eprintln!("Failed to draw event {}: {error:#}", event.id);
+        }
+    }
+    if !drew_upper {
+        tilemap.draw_upper(&mut image);
+    }
+
+    image
+        .save_with_format(&options.output, image::ImageFormat::Png)
+        .with_context(|| format!("failed to write \"{}\"", options.output.display()))?;
+
+    Ok(())
+}
+
+/// Read and parse a json file.
+fn read_json<T>(path: &Path) -> anyhow::Result<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let value = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read \"{}\"", path.display()))?;
+    let value = serde_json::from_str(&value)
+        .with_context(|| format!("failed to parse \"{}\"", path.display()))?;
+    Ok(value)
+}
+
+/// Draw the graphic of an event page, following `Sprite_Character`.
+fn draw_event(
+    image: &mut RgbaImage,
+    tilemap: &Tilemap<'_>,
+    image_loader: &mut ImageLoader,
+    page: &EventPage,
+    (x, y): (u32, u32),
+    tile_size: u32,
+) -> anyhow::Result<()> {
+    let graphic = &page.image;
+
+    // The sprite is anchored at the bottom center of the tile.
+    let center_x = i64::from(x * tile_size + tile_size / 2);
+    let bottom_y = i64::from((y + 1) * tile_size);
+
+    if graphic.tile_id > 0 {
+        let tile = TileId(i32::try_from(graphic.tile_id)?);
+        let dx = center_x - i64::from(tile_size / 2);
+        let dy = bottom_y - i64::from(tile_size);
+        tilemap.draw_normal_tile(image, tile, dx, dy);
+        return Ok(());
+    }
+
+    let name = graphic.character_name.as_str();
+    let Some(source) = image_loader.load_cached("characters", name)? else {
+        return Ok(());
+    };
+
+    let sign_len = name.len() - name.trim_start_matches(['!', '$']).len();
+    let sign = &name[..sign_len];
+    let is_big_character = sign.contains('$');
+    let is_object_character = sign.contains('!');
+
+    let (pw, ph) = if is_big_character {
+        (source.width() / 3, source.height() / 4)
+    } else {
+        (source.width() / 12, source.height() / 8)
+    };
+    let index = u32::from(graphic.character_index);
+    let (block_x, block_y) = if is_big_character {
+        (0, 0)
+    } else {
+        (index % 4 * 3, index / 4 * 4)
+    };
+    let pattern_x = u32::from(graphic.pattern);
+    let pattern_y = u32::from(graphic.direction.saturating_sub(2) / 2);
+    let sx = (block_x + pattern_x) * pw;
+    let sy = (block_y + pattern_y) * ph;
+
+    let shift_y = if is_object_character { 0 } else { 6 };
+    let dx = center_x - i64::from(pw / 2);
+    let dy = bottom_y - shift_y - i64::from(ph);
+    blit(image, source, sx, sy, pw, ph, dx, dy);
+
+    Ok(())
+}
+
+/// A loader for game images, which may be encrypted.
+struct ImageLoader {
+    root: PathBuf,
+    key: Option<rpgmvp::Key>,
+    cache: HashMap<(String, String), Option<RgbaImage>>,
+}
+
+impl ImageLoader {
+    fn new(root: PathBuf, key: Option<rpgmvp::Key>) -> Self {
+        Self {
+            root,
+            key,
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Load an image from a dir in `img`, caching the result.
+    fn load_cached(&mut self, dir: &str, name: &str) -> anyhow::Result<Option<&RgbaImage>> {
+        let cache_key = (dir.to_string(), name.to_string());
+        if !self.cache.contains_key(&cache_key) {
+            let image = self.load(dir, name)?;
+            self.cache.insert(cache_key.clone(), image);
+        }
+
+        Ok(self.cache[&cache_key].as_ref())
+    }
+
+    /// Load an image from a dir in `img`.
+    ///
+    /// Returns `None` if the name is empty.
+    /// Unencrypted images are preferred over encrypted images.
+    fn load(&self, dir: &str, name: &str) -> anyhow::Result<Option<RgbaImage>> {
+        if name.is_empty() {
+            return Ok(None);
+        }
+
+        let kind = rpgmvp::FileKind::Png;
+        let dir = self.root.join("img").join(dir);
+        for extension in [kind.extension(), kind.mv_extension(), kind.mz_extension()] {
+            let path = dir.join(format!("{name}.{extension}"));
+            let file = match File::open(&path) {
+                Ok(file) => file,
+                Err(error) if error.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(error) => {
+                    return Err(error)
+                        .with_context(|| format!("failed to open \"{}\"", path.display()));
+                }
+            };
+            let decrypt_context = || format!("failed to decrypt \"{}\"", path.display());
+            let mut reader =
+                rpgmv_asset::Reader::new(BufReader::new(file)).with_context(decrypt_context)?;
+            if let Some(key) = self.key {
+                reader.set_rpgmaker_key(key).with_context(decrypt_context)?;
+            }
+            reader
+                .verify_rpgmaker_key(kind)
+                .with_context(decrypt_context)?;
+
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes)?;
+
+            let image = image::load_from_memory_with_format(&bytes, image::ImageFormat::Png)
+                .with_context(|| format!("failed to decode \"{}\"", path.display()))?;
+            return Ok(Some(image.into_rgba8()));
+        }
+
+        anyhow::bail!("missing image \"{}\"", dir.join(name).display());
+    }
+}
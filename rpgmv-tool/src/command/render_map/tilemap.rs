@@ -0,0 +1,475 @@
+use super::autotile::FLOOR_AUTOTILE_TABLE;
+use super::autotile::WALL_AUTOTILE_TABLE;
+use super::autotile::WATERFALL_AUTOTILE_TABLE;
+use image::Pixel;
+use image::Rgba;
+use image::RgbaImage;
+use rpgmv_types::Map;
+use rpgmv_types::TileId;
+use rpgmv_types::TileSheet;
+use rpgmv_types::Tileset;
+
+/// The color of wall shadows.
+const SHADOW_COLOR: Rgba<u8> = Rgba([0, 0, 0, 128]);
+
+/// Something to draw in a tile, in draw order.
+#[derive(Debug, Clone, Copy)]
+enum TileDraw {
+    /// A normal tile or an autotile.
+    Tile(TileId),
+
+    /// The shadow bits of a wall.
+    Shadow(i32),
+
+    /// The bottom edge of the table tile above.
+    TableEdge(TileId),
+}
+
+/// A map renderer, following `Tilemap` in `rpg_core.js`.
+///
+/// Tiles are drawn as the first animation frame.
+pub struct Tilemap<'a> {
+    map: &'a Map,
+    tileset: &'a Tileset,
+    sheets: &'a [Option<RgbaImage>],
+    tile_width: u32,
+    tile_height: u32,
+}
+
+impl<'a> Tilemap<'a> {
+    /// Make a new tilemap.
+    ///
+    /// The sheets are the tileset images, in the order of [`Tileset::tileset_names`].
+    /// Missing sheets are skipped.
+    pub fn new(
+        map: &'a Map,
+        tileset: &'a Tileset,
+        sheets: &'a [Option<RgbaImage>],
+        tile_width: u32,
+        tile_height: u32,
+    ) -> Self {
+        Self {
+            map,
+            tileset,
+            sheets,
+            tile_width,
+            tile_height,
+        }
+    }
+
+    /// Make an empty image the size of the map.
+    pub fn new_image(&self) -> RgbaImage {
+        RgbaImage::new(
+            self.map.width * self.tile_width,
+            self.map.height * self.tile_height,
+        )
+    }
+
+    /// Draw the tiles that are below characters.
+    pub fn draw_lower(&self, image: &mut RgbaImage) {
+        self.draw_layer(image, true);
+    }
+
+    /// Draw the tiles that are above characters.
+    pub fn draw_upper(&self, image: &mut RgbaImage) {
+        self.draw_layer(image, false);
+    }
+
+    /// Draw a normal tile, like a tile that is used as an event graphic.
+    pub fn draw_normal_tile(&self, image: &mut RgbaImage, tile: TileId, dx: i64, dy: i64) {
+        let id = tile.0;
+        let set_number = if tile.sheet() == Some(TileSheet::A5) {
+            4
+        } else {
+            5 + id / 256
+        };
+        let sx = ((id / 128) % 2 * 8 + id % 8) as u32 * self.tile_width;
+        let sy = ((id % 256 / 8) % 16) as u32 * self.tile_height;
+
+        if let Some(source) = self.sheet(set_number) {
+            blit(
+                image,
+                source,
+                sx,
+                sy,
+                self.tile_width,
+                self.tile_height,
+                dx,
+                dy,
+            );
+        }
+    }
+
+    fn draw_layer(&self, image: &mut RgbaImage, lower: bool) {
+        for (x, y) in self.map.positions() {
+            let (lower_draws, upper_draws) = self.cell_draws(x, y);
+            let draws = if lower { lower_draws } else { upper_draws };
+
+            let dx = i64::from(x * self.tile_width);
+            let dy = i64::from(y * self.tile_height);
+            for draw in draws {
+                match draw {
+                    TileDraw::Tile(tile) => self.draw_tile(image, tile, dx, dy),
+                    TileDraw::Shadow(bits) => self.draw_shadow(image, bits, dx, dy),
+                    TileDraw::TableEdge(tile) => self.draw_table_edge(image, tile, dx, dy),
+                }
+            }
+        }
+    }
+
+    /// Get what to draw in a cell, for the lower and upper layers.
+    ///
+    /// This follows `Tilemap.prototype._paintTiles`.
+    fn cell_draws(&self, x: u32, y: u32) -> (Vec<TileDraw>, Vec<TileDraw>) {
+        let tile = |x, y, layer| self.map.tile(x, y, layer).unwrap_or_default();
+        let tile_id0 = tile(x, y, 0);
+        let tile_id1 = tile(x, y, 1);
+        let tile_id2 = tile(x, y, 2);
+        let tile_id3 = tile(x, y, 3);
+        let shadow_bits = self.map.shadow(x, y).unwrap_or(0);
+        let upper_tile_id1 = y.checked_sub(1).map(|y| tile(x, y, 1)).unwrap_or_default();
+
+        let mut lower = Vec::new();
+        let mut upper = Vec::new();
+        for tile in [tile_id0, tile_id1] {
+            if self.is_higher_tile(tile) {
+                upper.push(TileDraw::Tile(tile));
+            } else {
+                lower.push(TileDraw::Tile(tile));
+            }
+        }
+
+        lower.push(TileDraw::Shadow(shadow_bits));
+
+        if self.is_table_tile(upper_tile_id1)
+            && !self.is_table_tile(tile_id1)
+            && !tile_id0.is_shadowing()
+        {
+            lower.push(TileDraw::TableEdge(upper_tile_id1));
+        }
+
+        for tile in [tile_id2, tile_id3] {
+            if self.is_higher_tile(tile) {
+                upper.push(TileDraw::Tile(tile));
+            } else {
+                lower.push(TileDraw::Tile(tile));
+            }
+        }
+
+        (lower, upper)
+    }
+
+    fn sheet(&self, set_number: i32) -> Option<&RgbaImage> {
+        let set_number = usize::try_from(set_number).ok()?;
+        self.sheets.get(set_number)?.as_ref()
+    }
+
+    fn is_higher_tile(&self, tile: TileId) -> bool {
+        self.tileset
+            .tile_flags(tile)
+            .is_some_and(|flags| flags.is_star())
+    }
+
+    fn is_table_tile(&self, tile: TileId) -> bool {
+        tile.sheet() == Some(TileSheet::A2)
+            && self
+                .tileset
+                .tile_flags(tile)
+                .is_some_and(|flags| flags.is_counter())
+    }
+
+    fn draw_tile(&self, image: &mut RgbaImage, tile: TileId, dx: i64, dy: i64) {
+        if !tile.is_visible() {
+            return;
+        }
+
+        if tile.is_autotile() {
+            self.draw_autotile(image, tile, dx, dy);
+        } else {
+            self.draw_normal_tile(image, tile, dx, dy);
+        }
+    }
+
+    /// Draw an autotile, following `Tilemap.prototype._drawAutotile`.
+    fn draw_autotile(&self, image: &mut RgbaImage, tile: TileId, dx: i64, dy: i64) {
+        let (Some(kind), Some(shape)) = (tile.autotile_kind(), tile.autotile_shape()) else {
+            return;
+        };
+        let tx = kind % 8;
+        let ty = kind / 8;
+
+        let mut autotile_table: &[[[u32; 2]; 4]] = &FLOOR_AUTOTILE_TABLE;
+        let mut is_table = false;
+        let (set_number, bx, by) = match tile.sheet() {
+            Some(TileSheet::A1) => match kind {
+                0 => (0, 0, 0),
+                1 => (0, 0, 3),
+                2 => (0, 6, 0),
+                3 => (0, 6, 3),
+                _ => {
+                    let mut bx = tx / 4 * 8;
+                    let by = ty * 6 + tx / 2 % 2 * 3;
+                    if kind % 2 == 1 {
+                        bx += 6;
+                        autotile_table = &WATERFALL_AUTOTILE_TABLE;
+                    }
+                    (0, bx, by)
+                }
+            },
+            Some(TileSheet::A2) => {
+                is_table = self.is_table_tile(tile);
+                (1, tx * 2, (ty - 2) * 3)
+            }
+            Some(TileSheet::A3) => {
+                autotile_table = &WALL_AUTOTILE_TABLE;
+                (2, tx * 2, (ty - 6) * 2)
+            }
+            Some(TileSheet::A4) => {
+                if ty % 2 == 1 {
+                    autotile_table = &WALL_AUTOTILE_TABLE;
+                }
+                (3, tx * 2, ((ty - 10) * 5 + ty % 2) / 2)
+            }
+            _ => return,
+        };
+
+        let Some(table) = usize::try_from(shape)
+            .ok()
+            .and_then(|shape| autotile_table.get(shape))
+        else {
+            return;
+        };
+        let Some(source) = self.sheet(set_number) else {
+            return;
+        };
+
+        let w1 = self.tile_width / 2;
+        let h1 = self.tile_height / 2;
+        let bx = bx as u32;
+        let by = by as u32;
+        for (i, [qsx, qsy]) in table.iter().copied().enumerate() {
+            let i = i as u32;
+            let sx1 = (bx * 2 + qsx) * w1;
+            let sy1 = (by * 2 + qsy) * h1;
+            let dx1 = dx + i64::from((i % 2) * w1);
+            let dy1 = dy + i64::from((i / 2) * h1);
+
+            if is_table && (qsy == 1 || qsy == 5) {
+                let qsx2 = if qsy == 1 {
+                    [0, 3, 2, 1][qsx as usize]
+                } else {
+                    qsx
+                };
+                let qsy2 = 3;
+                let sx2 = (bx * 2 + qsx2) * w1;
+                let sy2 = (by * 2 + qsy2) * h1;
+                blit(image, source, sx2, sy2, w1, h1, dx1, dy1);
+                blit(
+                    image,
+                    source,
+                    sx1,
+                    sy1,
+                    w1,
+                    h1 / 2,
+                    dx1,
+                    dy1 + i64::from(h1 / 2),
+                );
+            } else {
+                blit(image, source, sx1, sy1, w1, h1, dx1, dy1);
+            }
+        }
+    }
+
+    /// Draw the bottom edge of a table, following `Tilemap.prototype._drawTableEdge`.
+    fn draw_table_edge(&self, image: &mut RgbaImage, tile: TileId, dx: i64, dy: i64) {
+        if tile.sheet() != Some(TileSheet::A2) {
+            return;
+        }
+        let (Some(kind), Some(shape)) = (tile.autotile_kind(), tile.autotile_shape()) else {
+            return;
+        };
+        let Some(table) = FLOOR_AUTOTILE_TABLE.get(shape as usize) else {
+            return;
+        };
+        let Some(source) = self.sheet(1) else {
+            return;
+        };
+
+        let tx = kind % 8;
+        let ty = kind / 8;
+        let bx = (tx * 2) as u32;
+        let by = ((ty - 2) * 3) as u32;
+        let w1 = self.tile_width / 2;
+        let h1 = self.tile_height / 2;
+        for i in 0..2 {
+            let [qsx, qsy] = table[2 + i as usize];
+            let sx1 = (bx * 2 + qsx) * w1;
+            let sy1 = (by * 2 + qsy) * h1 + h1 / 2;
+            let dx1 = dx + i64::from((i % 2) * w1);
+            let dy1 = dy + i64::from((i / 2) * h1);
+            blit(image, source, sx1, sy1, w1, h1 / 2, dx1, dy1);
+        }
+    }
+
+    /// Draw wall shadows, following `Tilemap.prototype._drawShadow`.
+    ///
+    /// Each bit is a quarter of the tile.
+    fn draw_shadow(&self, image: &mut RgbaImage, bits: i32, dx: i64, dy: i64) {
+        if bits & 0x0f == 0 {
+            return;
+        }
+
+        let w1 = self.tile_width / 2;
+        let h1 = self.tile_height / 2;
+        for i in 0..4 {
+            if bits & (1 << i) == 0 {
+                continue;
+            }
+
+            let dx1 = dx + i64::from((i % 2) * w1);
+            let dy1 = dy + i64::from((i / 2) * h1);
+            fill(image, SHADOW_COLOR, w1, h1, dx1, dy1);
+        }
+    }
+}
+
+/// Draw part of an image onto another, blending by alpha.
+///
+/// Parts of the rect that are outside of either image are skipped.
+#[allow(clippy::too_many_arguments)]
+pub fn blit(
+    dest: &mut RgbaImage,
+    source: &RgbaImage,
+    sx: u32,
+    sy: u32,
+    width: u32,
+    height: u32,
+    dx: i64,
+    dy: i64,
+) {
+    for y in 0..height {
+        for x in 0..width {
+            let Some(pixel) = source.get_pixel_checked(sx + x, sy + y) else {
+                continue;
+            };
+            let (Ok(dest_x), Ok(dest_y)) = (
+                u32::try_from(dx + i64::from(x)),
+                u32::try_from(dy + i64::from(y)),
+            ) else {
+                continue;
+            };
+            if let Some(dest_pixel) = dest.get_pixel_mut_checked(dest_x, dest_y) {
+                dest_pixel.blend(pixel);
+            }
+        }
+    }
+}
+
+/// Fill a rect with a color, blending by alpha.
+fn fill(dest: &mut RgbaImage, color: Rgba<u8>, width: u32, height: u32, dx: i64, dy: i64) {
+    for y in 0..height {
+        for x in 0..width {
+            let (Ok(dest_x), Ok(dest_y)) = (
+                u32::try_from(dx + i64::from(x)),
+                u32::try_from(dy + i64::from(y)),
+            ) else {
+                continue;
+            };
+            if let Some(dest_pixel) = dest.get_pixel_mut_checked(dest_x, dest_y) {
+                dest_pixel.blend(&color);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TILE_SIZE: u32 = 48;
+
+    /// Make a sheet where each half tile has a color that encodes its position.
+    fn make_sheet(width: u32, height: u32) -> RgbaImage {
+        RgbaImage::from_fn(width * TILE_SIZE, height * TILE_SIZE, |x, y| {
+            let qx = x / (TILE_SIZE / 2);
+            let qy = y / (TILE_SIZE / 2);
+            Rgba([qx as u8, qy as u8, 0, 255])
+        })
+    }
+
+    fn make_map(data: Vec<i32>) -> Map {
+        let map = serde_json::json!({
+            "autoplayBgm": false,
+            "autoplayBgs": false,
+            "battleback1Name": "",
+            "battleback2Name": "",
+            "bgm": { "name": "", "pan": 0, "pitch": 100, "volume": 90 },
+            "bgs": { "name": "", "pan": 0, "pitch": 100, "volume": 90 },
+            "disableDashing": false,
+            "displayName": "",
+            "encounterList": [],
+            "encounterStep": 30,
+            "height": 1,
+            "note": "",
+            "parallaxLoopX": false,
+            "parallaxLoopY": false,
+            "parallaxName": "",
+            "parallaxShow": true,
+            "parallaxSx": 0,
+            "parallaxSy": 0,
+            "scrollType": 0,
+            "specifyBattleback": false,
+            "tilesetId": 1,
+            "width": 2,
+            "data": data,
+            "events": [null],
+        });
+        serde_json::from_value(map).expect("invalid map")
+    }
+
+    #[test]
+    fn render() {
+        let tileset: Tileset = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "flags": vec![0; TileId::MAX.0 as usize],
+            "mode": 1,
+            "name": "",
+            "note": "",
+            "tilesetNames": ["", "A2", "", "", "", "B", "", "", ""],
+        }))
+        .expect("invalid tileset");
+        let sheets = [
+            None,
+            Some(make_sheet(16, 12)),
+            None,
+            None,
+            None,
+            Some(make_sheet(16, 16)),
+            None,
+            None,
+            None,
+        ];
+
+        // An A2 autotile with shape 0 and the last shape, and a B tile on top.
+        let mut data = vec![0; 2 * 6];
+        data[0] = TileId::A2.0;
+        data[1] = TileId::A2.0 + 47;
+        data[3] = 9;
+        let map = make_map(data);
+
+        let tilemap = Tilemap::new(&map, &tileset, &sheets, TILE_SIZE, TILE_SIZE);
+        let mut image = tilemap.new_image();
+        tilemap.draw_lower(&mut image);
+        assert!(image.dimensions() == (96, 48));
+
+        // Shape 0 uses the quarters [2, 4], [1, 4], [2, 3], [1, 3].
+        assert!(image.get_pixel(0, 0).0 == [2, 4, 0, 255]);
+        assert!(image.get_pixel(24, 0).0 == [1, 4, 0, 255]);
+        assert!(image.get_pixel(0, 24).0 == [2, 3, 0, 255]);
+        assert!(image.get_pixel(24, 24).0 == [1, 3, 0, 255]);
+
+        // B tile 9 is the second tile of the second row, which covers it.
+        assert!(image.get_pixel(48, 0).0 == [2, 2, 0, 255]);
+        assert!(image.get_pixel(95, 47).0 == [3, 3, 0, 255]);
+    }
+}
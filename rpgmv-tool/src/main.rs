@@ -22,8 +22,11 @@ enum SubCommand {
     #[command(name = "commands2py")]
     Commands2Py(self::command::commands2py::Options),
     EncryptPng(self::command::encrypt_png::Options),
+    EncryptGame(self::command::encrypt_game::Options),
     GenerateCompletions(self::command::generate_completions::Options),
     CheckLineSize(self::command::check_line_size::Options),
+    AuditKeys(self::command::audit_keys::Options),
+    RenderMap(self::command::render_map::Options),
 }
 
 fn main() -> anyhow::Result<()> {
@@ -33,10 +36,13 @@ fn main() -> anyhow::Result<()> {
         SubCommand::Decrypt(options) => self::command::decrypt::exec(options)?,
         SubCommand::Commands2Py(options) => self::command::commands2py::exec(options)?,
         SubCommand::EncryptPng(options) => self::command::encrypt_png::exec(options)?,
+        SubCommand::EncryptGame(options) => self::command::encrypt_game::exec(options)?,
         SubCommand::GenerateCompletions(options) => {
             self::command::generate_completions::exec(options)?
         }
         SubCommand::CheckLineSize(options) => self::command::check_line_size::exec(options)?,
+        SubCommand::AuditKeys(options) => self::command::audit_keys::exec(options)?,
+        SubCommand::RenderMap(options) => self::command::render_map::exec(options)?,
     }
 
     Ok(())
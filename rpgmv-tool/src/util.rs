@@ -1,4 +1,11 @@
+use anyhow::Context;
 use std::path::Path;
+use std::path::PathBuf;
+
+/// Check if a path is `-`, which means stdin or stdout.
+pub fn is_stdio(path: &Path) -> bool {
+    path == Path::new("-")
+}
 
 /// Try to get the metadata for a path.
 pub fn try_metadata<P>(path: P) -> std::io::Result<Option<std::fs::Metadata>>
@@ -11,3 +18,78 @@ where
         Err(error) => Err(error),
     }
 }
+
+/// Get the encryption key from a parsed `System.json`.
+///
+/// Returns `None` if the game does not have a key.
+/// Unencrypted games have an empty key.
+pub fn get_encryption_key(system: &serde_json::Value) -> anyhow::Result<Option<rpgmvp::Key>> {
+    let key = system
+        .get("encryptionKey")
+        .and_then(|key| key.as_str())
+        .filter(|key| !key.is_empty())
+        .map(|key| key.parse::<rpgmvp::Key>())
+        .transpose()
+        .context("invalid encryption key in System.json")?;
+
+    Ok(key)
+}
+
+/// Get the dir that holds a game's assets.
+///
+/// MV games keep their assets in the www dir.
+/// MZ games keep them next to the exe.
+pub fn get_game_assets_dir(game: &Path) -> anyhow::Result<PathBuf> {
+    let www_path = game.join("www");
+    let exists = www_path
+        .try_exists()
+        .with_context(|| format!("failed to check if \"{}\" exists", www_path.display()))?;
+
+    if exists {
+        Ok(www_path)
+    } else {
+        Ok(game.to_path_buf())
+    }
+}
+
+/// Try to read and parse a json file.
+///
+/// Returns `None` if the file does not exist.
+pub fn try_read_json(path: &Path) -> anyhow::Result<Option<serde_json::Value>> {
+    let value = match std::fs::read_to_string(path) {
+        Ok(value) => value,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(error) => {
+            return Err(error).with_context(|| format!("failed to read \"{}\"", path.display()));
+        }
+    };
+
+    let value = serde_json::from_str(&value)
+        .with_context(|| format!("failed to parse \"{}\"", path.display()))?;
+
+    Ok(Some(value))
+}
+
+/// Recursively list every file in a dir, sorted by path.
+pub fn list_files(dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        let dir_iter = std::fs::read_dir(&dir)
+            .with_context(|| format!("failed to read dir \"{}\"", dir.display()))?;
+        for entry in dir_iter {
+            let entry = entry?;
+            let file_type = entry.file_type()?;
+            let path = entry.path();
+
+            if file_type.is_dir() {
+                dirs.push(path);
+            } else {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+
+    Ok(files)
+}
@@ -0,0 +1,107 @@
+use crate::ExtraFields;
+use crate::unknown_fields::impl_unknown_fields;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct ActorTrait {
+    /// ?
+    pub code: u32,
+
+    /// ?
+    #[serde(rename = "dataId")]
+    pub data_id: u32,
+
+    /// ?
+    pub value: f64,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl_unknown_fields!(ActorTrait {});
+
+/// An actor
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct Actor {
+    /// The actor id
+    pub id: u32,
+
+    /// The battler image name
+    #[serde(rename = "battlerName")]
+    pub battler_name: String,
+
+    /// ?
+    #[serde(rename = "characterIndex")]
+    pub character_index: u32,
+
+    /// The character image name
+    #[serde(rename = "characterName")]
+    pub character_name: String,
+
+    /// The class id
+    #[serde(rename = "classId")]
+    pub class_id: u32,
+
+    /// The initial equipment ids, by equipment slot
+    pub equips: Vec<u32>,
+
+    /// ?
+    #[serde(rename = "faceIndex")]
+    pub face_index: u32,
+
+    /// The face image name
+    #[serde(rename = "faceName")]
+    pub face_name: String,
+
+    /// ?
+    pub traits: Vec<ActorTrait>,
+
+    /// ?
+    #[serde(rename = "initialLevel")]
+    pub initial_level: u32,
+
+    /// ?
+    #[serde(rename = "maxLevel")]
+    pub max_level: u32,
+
+    /// The actor name
+    pub name: String,
+
+    /// The actor nickname
+    pub nickname: String,
+
+    /// ?
+    pub note: String,
+
+    /// The actor profile
+    pub profile: String,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl_unknown_fields!(Actor { "traits" => traits });
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    // A hand-written sample in the format of a new RPGMaker MV project.
+    // TODO: Replace with a sample from a real project.
+    const ACTORS: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test-data/actors/Actors.json"
+    ));
+
+    #[test]
+    fn actors() {
+        let actors: Vec<Option<Actor>> = serde_json::from_str(ACTORS).expect("failed to parse");
+        assert!(actors[1].as_ref().expect("missing actor").name == "Harold");
+
+        let actors_ser = serde_json::to_string(&actors).expect("failed to serialize");
+        let actors_de: Vec<Option<Actor>> =
+            serde_json::from_str(&actors_ser).expect("failed to parse");
+
+        assert!(actors == actors_de);
+    }
+}
@@ -0,0 +1,105 @@
+use super::AudioFile;
+use crate::ExtraFields;
+use crate::unknown_fields::impl_unknown_fields;
+
+/// A timed flash or sound effect in an animation
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct AnimationTiming {
+    /// The flash color, as rgba
+    #[serde(rename = "flashColor")]
+    pub flash_color: Vec<u8>,
+
+    /// The flash duration, in frames
+    #[serde(rename = "flashDuration")]
+    pub flash_duration: u32,
+
+    /// ?
+    #[serde(rename = "flashScope")]
+    pub flash_scope: u32,
+
+    /// The frame this timing starts on
+    pub frame: u32,
+
+    /// The sound effect
+    pub se: Option<AudioFile>,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl_unknown_fields!(AnimationTiming { "se" => se });
+
+/// An animation
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct Animation {
+    /// The animation id
+    pub id: u32,
+
+    /// ?
+    #[serde(rename = "animation1Hue")]
+    pub animation1_hue: u32,
+
+    /// The first animation image name
+    #[serde(rename = "animation1Name")]
+    pub animation1_name: String,
+
+    /// ?
+    #[serde(rename = "animation2Hue")]
+    pub animation2_hue: u32,
+
+    /// The second animation image name
+    #[serde(rename = "animation2Name")]
+    pub animation2_name: String,
+
+    /// The cells of each frame.
+    ///
+    /// Each cell is the pattern, x, y, scale, rotation, mirror, opacity, and blend mode.
+    pub frames: Vec<Vec<Vec<i32>>>,
+
+    /// The animation name
+    pub name: String,
+
+    /// ?
+    pub position: u32,
+
+    /// ?
+    pub timings: Vec<AnimationTiming>,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl_unknown_fields!(Animation { "timings" => timings });
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    // A hand-written sample in the format of a new RPGMaker MV project.
+    // TODO: Replace with a sample from a real project.
+    const ANIMATIONS: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test-data/animations/Animations.json"
+    ));
+
+    #[test]
+    fn animations() {
+        let animations: Vec<Option<Animation>> =
+            serde_json::from_str(ANIMATIONS).expect("failed to parse");
+        assert!(
+            animations[1]
+                .as_ref()
+                .expect("missing animation")
+                .frames
+                .len()
+                == 4
+        );
+
+        let animations_ser = serde_json::to_string(&animations).expect("failed to serialize");
+        let animations_de: Vec<Option<Animation>> =
+            serde_json::from_str(&animations_ser).expect("failed to parse");
+
+        assert!(animations == animations_de);
+    }
+}
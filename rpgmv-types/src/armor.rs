@@ -1,5 +1,7 @@
+use crate::ExtraFields;
+use crate::unknown_fields::impl_unknown_fields;
+
 #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
-#[serde(deny_unknown_fields)]
 pub struct ArmorTrait {
     /// ?
     pub code: u32,
@@ -10,10 +12,15 @@ pub struct ArmorTrait {
 
     /// ?
     pub value: f32,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
+impl_unknown_fields!(ArmorTrait {});
+
 #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
-#[serde(deny_unknown_fields)]
 pub struct Armor {
     /// The troop id
     pub id: u32,
@@ -47,4 +54,10 @@ pub struct Armor {
 
     /// The cost of the armor.
     pub price: u32,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
+
+impl_unknown_fields!(Armor { "traits" => traits });
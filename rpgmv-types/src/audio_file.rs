@@ -1,5 +1,7 @@
+use crate::ExtraFields;
+use crate::unknown_fields::impl_unknown_fields;
+
 #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
-#[serde(deny_unknown_fields)]
 pub struct AudioFile {
     /// The file name
     pub name: String,
@@ -12,4 +14,10 @@ pub struct AudioFile {
 
     /// The volume
     pub volume: u8,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
+
+impl_unknown_fields!(AudioFile {});
@@ -0,0 +1,98 @@
+use crate::ExtraFields;
+use crate::unknown_fields::impl_unknown_fields;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct ClassTrait {
+    /// ?
+    pub code: u32,
+
+    /// ?
+    #[serde(rename = "dataId")]
+    pub data_id: u32,
+
+    /// ?
+    pub value: f64,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl_unknown_fields!(ClassTrait {});
+
+/// A skill learned by a class
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct ClassLearning {
+    /// The level the skill is learned at
+    pub level: u32,
+
+    /// ?
+    pub note: String,
+
+    /// The skill id
+    #[serde(rename = "skillId")]
+    pub skill_id: u32,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl_unknown_fields!(ClassLearning {});
+
+/// A class
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct Class {
+    /// The class id
+    pub id: u32,
+
+    /// The parameters of the exp curve
+    #[serde(rename = "expParams")]
+    pub exp_params: Vec<u32>,
+
+    /// ?
+    pub traits: Vec<ClassTrait>,
+
+    /// The skills learned by this class
+    pub learnings: Vec<ClassLearning>,
+
+    /// The class name
+    pub name: String,
+
+    /// ?
+    pub note: String,
+
+    /// The value of each param, by level
+    ///
+    /// This is indexed by param id, then level.
+    pub params: Vec<Vec<u32>>,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl_unknown_fields!(Class { "traits" => traits, "learnings" => learnings });
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    // A hand-written sample in the format of a new RPGMaker MV project.
+    // TODO: Replace with a sample from a real project.
+    const CLASSES: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test-data/classes/Classes.json"
+    ));
+
+    #[test]
+    fn classes() {
+        let classes: Vec<Option<Class>> = serde_json::from_str(CLASSES).expect("failed to parse");
+        assert!(classes[1].as_ref().expect("missing class").params.len() == 8);
+
+        let classes_ser = serde_json::to_string(&classes).expect("failed to serialize");
+        let classes_de: Vec<Option<Class>> =
+            serde_json::from_str(&classes_ser).expect("failed to parse");
+
+        assert!(classes == classes_de);
+    }
+}
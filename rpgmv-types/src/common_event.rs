@@ -1,8 +1,9 @@
 use super::EventCommand;
+use crate::ExtraFields;
+use crate::unknown_fields::impl_unknown_fields;
 
 /// Common event
 #[derive(Debug, serde::Deserialize, serde::Serialize, PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct CommonEvent {
     /// The id
     pub id: u32,
@@ -19,8 +20,14 @@ pub struct CommonEvent {
 
     /// ?
     pub trigger: u8,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
+impl_unknown_fields!(CommonEvent { "list" => list });
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -0,0 +1,464 @@
+use crate::Error;
+use crate::Map;
+use crate::System;
+use serde_json::ser::CharEscape;
+use serde_json::ser::CompactFormatter;
+use serde_json::ser::Formatter;
+use std::io::Write;
+
+/// The layout the editor uses for a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EditorLayout {
+    /// Everything on one line, like `System.json`.
+    Compact,
+
+    /// A top-level array with one entry per line, like `Actors.json` or `CommonEvents.json`.
+    ///
+    /// Each entry is compact.
+    Lines,
+
+    /// The layout of map files.
+    ///
+    /// The fields are on one line, followed by `data` on its own line,
+    /// and `events` with one event per line.
+    Map,
+}
+
+/// A file that the editor writes with a specific layout.
+pub trait EditorFile: serde::Serialize {
+    /// The layout of this file.
+    const LAYOUT: EditorLayout;
+}
+
+impl EditorFile for Map {
+    const LAYOUT: EditorLayout = EditorLayout::Map;
+}
+
+impl EditorFile for System {
+    const LAYOUT: EditorLayout = EditorLayout::Compact;
+}
+
+impl<T> EditorFile for Vec<Option<T>>
+where
+    T: serde::Serialize,
+{
+    const LAYOUT: EditorLayout = EditorLayout::Lines;
+}
+
+/// Serialize a file to a string, formatted exactly like the editor would.
+///
+/// Loading and saving an unchanged file produces identical bytes.
+pub fn to_editor_string<T>(value: &T) -> Result<String, Error>
+where
+    T: EditorFile + ?Sized,
+{
+    let mut output = Vec::new();
+    to_editor_writer(&mut output, value)?;
+
+    // Formatting only ever writes valid utf8.
+    Ok(String::from_utf8(output).expect("invalid utf8"))
+}
+
+/// Serialize a file to a writer, formatted exactly like the editor would.
+pub fn to_editor_writer<W, T>(writer: W, value: &T) -> Result<(), Error>
+where
+    W: Write,
+    T: EditorFile + ?Sized,
+{
+    to_editor_writer_with_layout(writer, value, T::LAYOUT)
+}
+
+/// Serialize a value to a writer, formatted like the editor would with the given layout.
+///
+/// This is useful for values that are not typed, like a [`serde_json::Value`].
+pub fn to_editor_writer_with_layout<W, T>(
+    writer: W,
+    value: &T,
+    layout: EditorLayout,
+) -> Result<(), Error>
+where
+    W: Write,
+    T: serde::Serialize + ?Sized,
+{
+    let formatter = EditorFormatter::new(layout);
+    let mut serializer = serde_json::Serializer::with_formatter(writer, formatter);
+    value.serialize(&mut serializer)?;
+    Ok(())
+}
+
+/// A formatter which matches the output of the editor.
+struct EditorFormatter {
+    layout: EditorLayout,
+    depth: usize,
+
+    /// The depth of the array which has one entry per line, if in one.
+    lines_depth: Option<usize>,
+
+    /// The buffered top-level map key, if one is being written.
+    ///
+    /// Some keys start on a new line, which is only known after the key is written.
+    key: Option<Vec<u8>>,
+
+    /// The last top-level map key, including quotes.
+    last_key: Vec<u8>,
+}
+
+impl EditorFormatter {
+    fn new(layout: EditorLayout) -> Self {
+        Self {
+            layout,
+            depth: 0,
+            lines_depth: None,
+            key: None,
+            last_key: Vec::new(),
+        }
+    }
+
+    /// Check if the object at the current depth is the top-level map object.
+    fn is_map_root(&self) -> bool {
+        self.layout == EditorLayout::Map && self.depth == 1
+    }
+
+    /// Check if the array at the current depth has one entry per line.
+    fn is_lines_array(&self) -> bool {
+        match self.layout {
+            EditorLayout::Compact => false,
+            EditorLayout::Lines => self.depth == 1,
+            EditorLayout::Map => self.depth == 2 && self.last_key == b"\"events\"",
+        }
+    }
+}
+
+impl Formatter for EditorFormatter {
+    fn write_f32<W>(&mut self, writer: &mut W, value: f32) -> std::io::Result<()>
+    where
+        W: ?Sized + Write,
+    {
+        write_js_number(writer, value.is_finite(), &format!("{value:e}"))
+    }
+
+    fn write_f64<W>(&mut self, writer: &mut W, value: f64) -> std::io::Result<()>
+    where
+        W: ?Sized + Write,
+    {
+        write_js_number(writer, value.is_finite(), &format!("{value:e}"))
+    }
+
+    fn begin_string<W>(&mut self, writer: &mut W) -> std::io::Result<()>
+    where
+        W: ?Sized + Write,
+    {
+        match self.key.as_mut() {
+            Some(key) => CompactFormatter.begin_string(key),
+            None => CompactFormatter.begin_string(writer),
+        }
+    }
+
+    fn end_string<W>(&mut self, writer: &mut W) -> std::io::Result<()>
+    where
+        W: ?Sized + Write,
+    {
+        match self.key.as_mut() {
+            Some(key) => CompactFormatter.end_string(key),
+            None => CompactFormatter.end_string(writer),
+        }
+    }
+
+    fn write_string_fragment<W>(&mut self, writer: &mut W, fragment: &str) -> std::io::Result<()>
+    where
+        W: ?Sized + Write,
+    {
+        match self.key.as_mut() {
+            Some(key) => CompactFormatter.write_string_fragment(key, fragment),
+            None => CompactFormatter.write_string_fragment(writer, fragment),
+        }
+    }
+
+    fn write_char_escape<W>(
+        &mut self,
+        writer: &mut W,
+        char_escape: CharEscape,
+    ) -> std::io::Result<()>
+    where
+        W: ?Sized + Write,
+    {
+        match self.key.as_mut() {
+            Some(key) => CompactFormatter.write_char_escape(key, char_escape),
+            None => CompactFormatter.write_char_escape(writer, char_escape),
+        }
+    }
+
+    fn begin_array<W>(&mut self, writer: &mut W) -> std::io::Result<()>
+    where
+        W: ?Sized + Write,
+    {
+        self.depth += 1;
+        if self.is_lines_array() {
+            self.lines_depth = Some(self.depth);
+        }
+        writer.write_all(b"[")
+    }
+
+    fn end_array<W>(&mut self, writer: &mut W) -> std::io::Result<()>
+    where
+        W: ?Sized + Write,
+    {
+        let result = if self.lines_depth == Some(self.depth) {
+            self.lines_depth = None;
+            writer.write_all(b"\n]")
+        } else {
+            writer.write_all(b"]")
+        };
+        self.depth -= 1;
+        result
+    }
+
+    fn begin_array_value<W>(&mut self, writer: &mut W, first: bool) -> std::io::Result<()>
+    where
+        W: ?Sized + Write,
+    {
+        match (self.lines_depth == Some(self.depth), first) {
+            (true, true) => writer.write_all(b"\n"),
+            (true, false) => writer.write_all(b",\n"),
+            (false, true) => Ok(()),
+            (false, false) => writer.write_all(b","),
+        }
+    }
+
+    fn begin_object<W>(&mut self, writer: &mut W) -> std::io::Result<()>
+    where
+        W: ?Sized + Write,
+    {
+        self.depth += 1;
+        if self.is_map_root() {
+            writer.write_all(b"{\n")
+        } else {
+            writer.write_all(b"{")
+        }
+    }
+
+    fn end_object<W>(&mut self, writer: &mut W) -> std::io::Result<()>
+    where
+        W: ?Sized + Write,
+    {
+        let result = if self.is_map_root() {
+            writer.write_all(b"\n}")
+        } else {
+            writer.write_all(b"}")
+        };
+        self.depth -= 1;
+        result
+    }
+
+    fn begin_object_key<W>(&mut self, writer: &mut W, first: bool) -> std::io::Result<()>
+    where
+        W: ?Sized + Write,
+    {
+        if self.is_map_root() {
+            self.key = Some(Vec::new());
+        }
+        if first {
+            Ok(())
+        } else {
+            writer.write_all(b",")
+        }
+    }
+
+    fn end_object_key<W>(&mut self, writer: &mut W) -> std::io::Result<()>
+    where
+        W: ?Sized + Write,
+    {
+        if let Some(key) = self.key.take() {
+            if key == b"\"data\"" || key == b"\"events\"" {
+                writer.write_all(b"\n")?;
+            }
+            writer.write_all(&key)?;
+            self.last_key = key;
+        }
+        Ok(())
+    }
+}
+
+/// Write a number like javascript's `Number.prototype.toString`.
+///
+/// The number is given in Rust's shortest exponential form, like `-1.25e3`.
+fn write_js_number<W>(writer: &mut W, is_finite: bool, exponential: &str) -> std::io::Result<()>
+where
+    W: ?Sized + Write,
+{
+    // JSON.stringify writes non-finite numbers as null.
+    if !is_finite {
+        return writer.write_all(b"null");
+    }
+
+    let (sign, exponential) = match exponential.strip_prefix('-') {
+        Some(exponential) => ("-", exponential),
+        None => ("", exponential),
+    };
+    let (mantissa, exponent) = exponential
+        .split_once('e')
+        .expect("missing exponent in float");
+    let exponent: i32 = exponent.parse().expect("invalid exponent in float");
+    let digits: String = mantissa.chars().filter(|c| *c != '.').collect();
+
+    // This also handles negative zero, which is written as 0.
+    if digits == "0" {
+        return writer.write_all(b"0");
+    }
+
+    // The position of the decimal point, relative to the start of the digits.
+    let point = exponent + 1;
+    let digits_len = i32::try_from(digits.len()).expect("too many digits");
+
+    let output = if digits_len <= point && point <= 21 {
+        let zeros = "0".repeat((point - digits_len) as usize);
+        format!("{sign}{digits}{zeros}")
+    } else if 0 < point && point <= 21 {
+        let (int, frac) = digits.split_at(point as usize);
+        format!("{sign}{int}.{frac}")
+    } else if -6 < point && point <= 0 {
+        let zeros = "0".repeat(-point as usize);
+        format!("{sign}0.{zeros}{digits}")
+    } else {
+        let exponent_sign = if exponent < 0 { '-' } else { '+' };
+        let exponent = exponent.abs();
+        let (first, rest) = digits.split_at(1);
+        if rest.is_empty() {
+            format!("{sign}{first}e{exponent_sign}{exponent}")
+        } else {
+            format!("{sign}{first}.{rest}e{exponent_sign}{exponent}")
+        }
+    };
+
+    writer.write_all(output.as_bytes())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Actor;
+    use crate::Animation;
+    use crate::Class;
+    use crate::CommonEvent;
+    use crate::Enemy;
+    use crate::MapInfo;
+    use crate::State;
+    use crate::Tileset;
+    use serde::de::DeserializeOwned;
+
+    /// Check that a file in the format of a new RPGMaker MV project is written back unchanged.
+    fn assert_round_trip<T>(input: &str)
+    where
+        T: EditorFile + DeserializeOwned,
+    {
+        let value: T = serde_json::from_str(input).expect("failed to parse");
+        let output = to_editor_string(&value).expect("failed to serialize");
+        assert!(output == input, "{output}");
+    }
+
+    /// Check that a file is written in the editor's layout without losing anything,
+    /// and that writing it again gives the same bytes.
+    ///
+    /// This is for files that are not already in the editor's layout.
+    fn assert_stable_round_trip<T>(input: &str)
+    where
+        T: EditorFile + DeserializeOwned + PartialEq + std::fmt::Debug,
+    {
+        let value: T = serde_json::from_str(input).expect("failed to parse");
+        let output = to_editor_string(&value).expect("failed to serialize");
+        let value_de: T = serde_json::from_str(&output).expect("failed to parse");
+        assert!(value == value_de);
+
+        let output_de = to_editor_string(&value_de).expect("failed to serialize");
+        assert!(output == output_de);
+    }
+
+    macro_rules! test_data {
+        ($path:literal) => {
+            include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/test-data/", $path))
+        };
+    }
+
+    #[test]
+    fn round_trip_test_data() {
+        assert_round_trip::<Vec<Option<Actor>>>(test_data!("actors/Actors.json"));
+        assert_round_trip::<Vec<Option<Animation>>>(test_data!("animations/Animations.json"));
+        assert_round_trip::<Vec<Option<Class>>>(test_data!("classes/Classes.json"));
+        assert_round_trip::<Vec<Option<Enemy>>>(test_data!("enemies/Enemies.json"));
+        assert_round_trip::<Vec<Option<MapInfo>>>(test_data!("map-infos/MapInfos.json"));
+        assert_round_trip::<Vec<Option<State>>>(test_data!("states/States.json"));
+        assert_round_trip::<Vec<Option<Tileset>>>(test_data!("tilesets/Tilesets.json"));
+        assert_round_trip::<System>(test_data!("system/System-mv.json"));
+
+        // The encryption fields are added by the deployer, not the editor.
+        // MZ's deployer writes them in a different order, so this is only checked for equality.
+        let system: System =
+            serde_json::from_str(test_data!("system/System-mz.json")).expect("failed to parse");
+        let system_ser = to_editor_string(&system).expect("failed to serialize");
+        let system_de: System = serde_json::from_str(&system_ser).expect("failed to parse");
+        assert!(system == system_de);
+    }
+
+    #[test]
+    fn round_trip_real_data() {
+        // These are taken from real projects, but were pretty-printed,
+        // so they cannot be compared byte for byte.
+        assert_stable_round_trip::<Vec<Option<CommonEvent>>>(test_data!(
+            "common-events/CommonEvents1.json"
+        ));
+        assert_stable_round_trip::<Map>(test_data!("maps/Map001.json"));
+        assert_stable_round_trip::<Map>(test_data!("maps/Map002.json"));
+        assert_stable_round_trip::<Map>(test_data!("maps/Map004.json"));
+    }
+
+    #[test]
+    fn round_trip_map() {
+        let input = concat!(
+            "{\n",
+            r#""autoplayBgm":false,"autoplayBgs":false,"battleback1Name":"","battleback2Name":"","#,
+            r#""bgm":{"name":"","pan":0,"pitch":100,"volume":90},"#,
+            r#""bgs":{"name":"","pan":0,"pitch":100,"volume":90},"#,
+            r#""disableDashing":false,"displayName":"","encounterList":[],"encounterStep":30,"#,
+            r#""height":1,"note":"","parallaxLoopX":false,"parallaxLoopY":false,"parallaxName":"","#,
+            r#""parallaxShow":true,"parallaxSx":0,"parallaxSy":0,"scrollType":0,"#,
+            r#""specifyBattleback":false,"tilesetId":1,"width":2,"#,
+            "\n",
+            r#""data":[2816,2816,0,0,0,0,0,0,0,0,0,0],"#,
+            "\n",
+            r#""events":["#,
+            "\n",
+            "null,\n",
+            r#"{"id":1,"name":"EV001","note":"","pages":[{"conditions":{"actorId":1,"actorValid":false,"itemId":1,"itemValid":false,"selfSwitchCh":"A","selfSwitchValid":false,"switch1Id":1,"switch1Valid":false,"switch2Id":1,"switch2Valid":false,"variableId":1,"variableValid":false,"variableValue":0},"directionFix":false,"image":{"tileId":0,"characterName":"","direction":2,"pattern":0,"characterIndex":0},"list":[{"code":101,"indent":0,"parameters":["",0,0,2]},{"code":401,"indent":0,"parameters":["Line\n\"1\""]},{"code":205,"indent":0,"parameters":[-1,{"list":[{"code":14,"parameters":[0,-1],"indent":null},{"code":0}],"repeat":false,"skippable":false,"wait":true}]},{"code":505,"indent":0,"parameters":[{"code":14,"parameters":[0,-1],"indent":null}]},{"code":0,"indent":0,"parameters":[]}],"moveFrequency":3,"moveRoute":{"list":[{"code":0,"parameters":[]}],"repeat":true,"skippable":false,"wait":false},"moveSpeed":3,"moveType":0,"priorityType":0,"stepAnime":false,"through":false,"trigger":0,"walkAnime":true}],"x":1,"y":0},"#,
+            "\n",
+            "null\n",
+            "]\n",
+            "}"
+        );
+
+        assert_round_trip::<Map>(input);
+    }
+
+    #[test]
+    fn js_number() {
+        let format_f64 = |value: f64| {
+            let mut output = Vec::new();
+            write_js_number(&mut output, value.is_finite(), &format!("{value:e}"))
+                .expect("failed to write");
+            String::from_utf8(output).expect("invalid utf8")
+        };
+
+        assert!(format_f64(0.95) == "0.95");
+        assert!(format_f64(408.0) == "408");
+        assert!(format_f64(-1142.5) == "-1142.5");
+        assert!(format_f64(-0.0) == "0");
+        assert!(format_f64(0.000001) == "0.000001");
+        assert!(format_f64(1e-7) == "1e-7");
+        assert!(format_f64(1.5e-7) == "1.5e-7");
+        assert!(format_f64(1e20) == "100000000000000000000");
+        assert!(format_f64(1e21) == "1e+21");
+        assert!(format_f64(f64::NAN) == "null");
+
+        let value: Vec<Option<f32>> = vec![None, Some(0.95)];
+        assert!(to_editor_string(&value).expect("failed to serialize") == "[\nnull,\n0.95\n]");
+    }
+}
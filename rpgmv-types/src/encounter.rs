@@ -1,6 +1,8 @@
+use crate::ExtraFields;
+use crate::unknown_fields::impl_unknown_fields;
+
 /// An encounter
 #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Encounter {
     /// ?
     #[serde(rename = "regionSet")]
@@ -12,4 +14,10 @@ pub struct Encounter {
 
     /// The relative weight of this event
     pub weight: u16,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
+
+impl_unknown_fields!(Encounter {});
@@ -0,0 +1,139 @@
+use crate::ExtraFields;
+use crate::unknown_fields::impl_unknown_fields;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct EnemyTrait {
+    /// ?
+    pub code: u32,
+
+    /// ?
+    #[serde(rename = "dataId")]
+    pub data_id: u32,
+
+    /// ?
+    pub value: f64,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl_unknown_fields!(EnemyTrait {});
+
+/// An action an enemy may take
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct EnemyAction {
+    /// ?
+    #[serde(rename = "conditionParam1")]
+    pub condition_param_1: f64,
+
+    /// ?
+    #[serde(rename = "conditionParam2")]
+    pub condition_param_2: f64,
+
+    /// ?
+    #[serde(rename = "conditionType")]
+    pub condition_type: u32,
+
+    /// ?
+    pub rating: u32,
+
+    /// The skill id
+    #[serde(rename = "skillId")]
+    pub skill_id: u32,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl_unknown_fields!(EnemyAction {});
+
+/// An item an enemy may drop
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct EnemyDropItem {
+    /// The id of the item, weapon, or armor
+    #[serde(rename = "dataId")]
+    pub data_id: u32,
+
+    /// The drop chance is 1 / denominator
+    pub denominator: u32,
+
+    /// ?
+    pub kind: u32,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl_unknown_fields!(EnemyDropItem {});
+
+/// An enemy
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct Enemy {
+    /// The enemy id
+    pub id: u32,
+
+    /// ?
+    pub actions: Vec<EnemyAction>,
+
+    /// ?
+    #[serde(rename = "battlerHue")]
+    pub battler_hue: u32,
+
+    /// The battler image name
+    #[serde(rename = "battlerName")]
+    pub battler_name: String,
+
+    /// ?
+    #[serde(rename = "dropItems")]
+    pub drop_items: Vec<EnemyDropItem>,
+
+    /// The exp given when defeated
+    pub exp: u32,
+
+    /// ?
+    pub traits: Vec<EnemyTrait>,
+
+    /// The gold given when defeated
+    pub gold: u32,
+
+    /// The enemy name
+    pub name: String,
+
+    /// ?
+    pub note: String,
+
+    /// ?
+    pub params: Vec<u32>,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl_unknown_fields!(Enemy { "actions" => actions, "dropItems" => drop_items, "traits" => traits });
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    // A hand-written sample in the format of a new RPGMaker MV project.
+    // TODO: Replace with a sample from a real project.
+    const ENEMIES: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test-data/enemies/Enemies.json"
+    ));
+
+    #[test]
+    fn enemies() {
+        let enemies: Vec<Option<Enemy>> = serde_json::from_str(ENEMIES).expect("failed to parse");
+        assert!(enemies[1].as_ref().expect("missing enemy").name == "Bat");
+
+        let enemies_ser = serde_json::to_string(&enemies).expect("failed to serialize");
+        let enemies_de: Vec<Option<Enemy>> =
+            serde_json::from_str(&enemies_ser).expect("failed to parse");
+
+        assert!(enemies == enemies_de);
+    }
+}
@@ -1,8 +1,9 @@
 use crate::EventPage;
+use crate::ExtraFields;
+use crate::unknown_fields::impl_unknown_fields;
 
 /// An event
 #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Event {
     /// The event id
     pub id: u32,
@@ -21,4 +22,10 @@ pub struct Event {
 
     /// ?
     pub y: u8,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
+
+impl_unknown_fields!(Event { "pages" => pages });
@@ -1,6 +1,8 @@
+use crate::ExtraFields;
+use crate::unknown_fields::impl_unknown_fields;
+
 /// An event command
 #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct EventCommand {
     /// The event code
     pub code: u32,
@@ -14,5 +16,12 @@ pub struct EventCommand {
     /// I'm not sure if this is part of the core engine or not.
     ///
     /// This is only for MZ games.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub collapsed: Option<bool>,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
+
+impl_unknown_fields!(EventCommand {});
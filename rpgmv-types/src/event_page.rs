@@ -1,11 +1,12 @@
 use crate::EventCommand;
 use crate::EventPageCondition;
+use crate::ExtraFields;
 use crate::ImageFile;
 use crate::MoveRoute;
+use crate::unknown_fields::impl_unknown_fields;
 
 /// An event page
 #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct EventPage {
     /// ?
     pub conditions: EventPageCondition,
@@ -53,4 +54,15 @@ pub struct EventPage {
     /// ?
     #[serde(rename = "walkAnime")]
     pub walk_anime: bool,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
+
+impl_unknown_fields!(EventPage {
+    "conditions" => conditions,
+    "image" => image,
+    "list" => list,
+    "moveRoute" => move_route,
+});
@@ -1,6 +1,8 @@
+use crate::ExtraFields;
+use crate::unknown_fields::impl_unknown_fields;
+
 /// An event page condition set
 #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct EventPageCondition {
     /// The actor id
     #[serde(rename = "actorId")]
@@ -53,4 +55,10 @@ pub struct EventPageCondition {
     /// ?
     #[serde(rename = "variableValue")]
     pub variable_value: i32,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
+
+impl_unknown_fields!(EventPageCondition {});
@@ -1,6 +1,8 @@
+use crate::ExtraFields;
+use crate::unknown_fields::impl_unknown_fields;
+
 /// An image
 #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
-#[serde(deny_unknown_fields)]
 pub struct ImageFile {
     /// ?
     #[serde(rename = "tileId")]
@@ -19,4 +21,10 @@ pub struct ImageFile {
     /// ?
     #[serde(rename = "characterIndex")]
     pub character_index: u8,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
+
+impl_unknown_fields!(ImageFile {});
@@ -1,5 +1,7 @@
+use crate::ExtraFields;
+use crate::unknown_fields::impl_unknown_fields;
+
 #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
-#[serde(deny_unknown_fields)]
 pub struct ItemDamage {
     /// ?
     pub critical: bool,
@@ -17,10 +19,15 @@ pub struct ItemDamage {
 
     /// ?
     pub variance: u32,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
+impl_unknown_fields!(ItemDamage {});
+
 #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
-#[serde(deny_unknown_fields)]
 pub struct ItemEffect {
     /// ?
     pub code: u32,
@@ -33,10 +40,15 @@ pub struct ItemEffect {
 
     /// ?
     pub value2: f64,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
+impl_unknown_fields!(ItemEffect {});
+
 #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
-#[serde(deny_unknown_fields)]
 pub struct Item {
     /// The item id
     pub id: u32,
@@ -99,4 +111,10 @@ pub struct Item {
     /// ?
     #[serde(rename = "tpGain")]
     pub tp_gain: u32,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
+
+impl_unknown_fields!(Item { "damage" => damage, "effects" => effects });
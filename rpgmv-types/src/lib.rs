@@ -1,7 +1,12 @@
+mod actor;
+mod animation;
 mod armor;
 mod audio_file;
+mod class;
 mod common_event;
+mod editor_json;
 mod encounter;
+mod enemy;
 mod event;
 mod event_command;
 mod event_page;
@@ -9,21 +14,38 @@ mod event_page_condition;
 mod image_file;
 mod item;
 mod map;
+mod map_info;
 mod move_command;
 mod move_route;
+mod param;
 mod plugin;
 mod skill;
+mod state;
 mod system;
+mod tile_id;
+mod tileset;
 mod troop;
 mod troop_member;
 mod troop_page;
 mod troop_page_condition;
+mod typed_command;
+mod typed_move_command;
+mod unknown_fields;
 mod weapon;
 
+pub use self::actor::Actor;
+pub use self::animation::Animation;
 pub use self::armor::Armor;
 pub use self::audio_file::AudioFile;
+pub use self::class::Class;
 pub use self::common_event::CommonEvent;
+pub use self::editor_json::EditorFile;
+pub use self::editor_json::EditorLayout;
+pub use self::editor_json::to_editor_string;
+pub use self::editor_json::to_editor_writer;
+pub use self::editor_json::to_editor_writer_with_layout;
 pub use self::encounter::Encounter;
+pub use self::enemy::Enemy;
 pub use self::event::Event;
 pub use self::event_command::EventCommand;
 pub use self::event_page::EventPage;
@@ -31,13 +53,63 @@ pub use self::event_page_condition::EventPageCondition;
 pub use self::image_file::ImageFile;
 pub use self::item::Item;
 pub use self::map::Map;
+pub use self::map::MapLayer;
+pub use self::map_info::MapInfo;
 pub use self::move_command::MoveCommand;
 pub use self::move_route::MoveRoute;
 pub use self::plugin::Plugin;
 pub use self::skill::Skill;
+pub use self::state::State;
 pub use self::system::System;
+pub use self::tile_id::TileFlags;
+pub use self::tile_id::TileId;
+pub use self::tile_id::TileSheet;
+pub use self::tileset::Tileset;
 pub use self::troop::Troop;
 pub use self::troop_member::TroopMember;
 pub use self::troop_page::TroopPage;
 pub use self::troop_page_condition::TroopPageCondition;
+pub use self::typed_command::ActorCheck;
+pub use self::typed_command::CommandAudioFile;
+pub use self::typed_command::CommandData;
+pub use self::typed_command::Condition;
+pub use self::typed_command::EnemyCheck;
+pub use self::typed_command::Operand;
+pub use self::typed_command::TypedCommand;
+pub use self::typed_command::VariableOperand;
+pub use self::typed_move_command::MoveCommandData;
+pub use self::typed_move_command::TypedMoveCommand;
+pub use self::unknown_fields::ExtraFields;
+pub use self::unknown_fields::UnknownFields;
+pub use self::unknown_fields::from_str_strict;
 pub use self::weapon::Weapon;
+
+/// The error type
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("json error")]
+    Json(#[from] serde_json::Error),
+
+    #[error("unknown fields: {}", fields.join(", "))]
+    UnknownFields { fields: Vec<String> },
+
+    #[error("unknown command code {code}")]
+    UnknownCode { code: u32 },
+
+    #[error("command {code} is missing parameter {index}")]
+    MissingParameter { code: u32, index: usize },
+
+    #[error("command {code} has {len} parameters, expected {expected}")]
+    TooManyParameters {
+        code: u32,
+        len: usize,
+        expected: usize,
+    },
+
+    #[error("parameter {index} of command {code} is not a valid {expected}")]
+    InvalidParameter {
+        code: u32,
+        index: usize,
+        expected: &'static str,
+    },
+}
@@ -1,10 +1,12 @@
 use super::AudioFile;
 use super::Encounter;
 use super::Event;
+use crate::ExtraFields;
+use crate::TileId;
+use crate::unknown_fields::impl_unknown_fields;
 
 /// A Map
 #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Map {
     /// ?
     #[serde(rename = "autoplayBgm")]
@@ -89,11 +91,142 @@ pub struct Map {
     /// ?
     pub width: u32,
 
-    /// ?
+    /// The map layers, as a flat array.
+    ///
+    /// See [`MapLayer`] for the layout.
     pub data: Vec<i32>,
 
     /// ?
     pub events: Vec<Option<Event>>,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl_unknown_fields!(Map {
+    "bgm" => bgm,
+    "bgs" => bgs,
+    "encounterList" => encounter_list,
+    "events" => events,
+});
+
+impl Map {
+    /// Get the index of a position in a layer of [`Map::data`].
+    ///
+    /// Returns `None` if the position or layer is out of bounds.
+    pub fn data_index(&self, x: u32, y: u32, layer: MapLayer) -> Option<usize> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        let z = usize::from(layer.index()?);
+        let width = usize::try_from(self.width).ok()?;
+        let height = usize::try_from(self.height).ok()?;
+        let x = usize::try_from(x).ok()?;
+        let y = usize::try_from(y).ok()?;
+
+        let index = (z * height + y) * width + x;
+        if index >= self.data.len() {
+            return None;
+        }
+
+        Some(index)
+    }
+
+    /// Get the raw value at a position in a layer.
+    pub fn get(&self, x: u32, y: u32, layer: MapLayer) -> Option<i32> {
+        let index = self.data_index(x, y, layer)?;
+        Some(self.data[index])
+    }
+
+    /// Set the raw value at a position in a layer.
+    ///
+    /// Returns the old value, or `None` if the position or layer is out of bounds.
+    pub fn set(&mut self, x: u32, y: u32, layer: MapLayer, value: i32) -> Option<i32> {
+        let index = self.data_index(x, y, layer)?;
+        Some(std::mem::replace(&mut self.data[index], value))
+    }
+
+    /// Get the tile at a position in a tile layer, from 0 to 3.
+    pub fn tile(&self, x: u32, y: u32, layer: u8) -> Option<TileId> {
+        self.get(x, y, MapLayer::Tile(layer)).map(TileId)
+    }
+
+    /// Set the tile at a position in a tile layer, from 0 to 3.
+    ///
+    /// Returns the old tile, or `None` if the position or layer is out of bounds.
+    pub fn set_tile(&mut self, x: u32, y: u32, layer: u8, tile: TileId) -> Option<TileId> {
+        self.set(x, y, MapLayer::Tile(layer), tile.0).map(TileId)
+    }
+
+    /// Get the shadow bits at a position.
+    ///
+    /// Each bit is a quarter of the tile, in the order top left, top right, bottom left, bottom right.
+    pub fn shadow(&self, x: u32, y: u32) -> Option<i32> {
+        self.get(x, y, MapLayer::Shadow)
+    }
+
+    /// Set the shadow bits at a position.
+    pub fn set_shadow(&mut self, x: u32, y: u32, shadow: i32) -> Option<i32> {
+        self.set(x, y, MapLayer::Shadow, shadow)
+    }
+
+    /// Get the region id at a position.
+    pub fn region(&self, x: u32, y: u32) -> Option<i32> {
+        self.get(x, y, MapLayer::Region)
+    }
+
+    /// Set the region id at a position.
+    pub fn set_region(&mut self, x: u32, y: u32, region: i32) -> Option<i32> {
+        self.set(x, y, MapLayer::Region, region)
+    }
+
+    /// Iterate over every position of the map, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (u32, u32)> + use<> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Iterate over the positions that have the given region id.
+    pub fn region_positions(&self, region: i32) -> impl Iterator<Item = (u32, u32)> + '_ {
+        self.positions()
+            .filter(move |(x, y)| self.region(*x, *y) == Some(region))
+    }
+}
+
+/// A layer of [`Map::data`].
+///
+/// The data is made of 6 layers, each of which is `width * height` values, stored row by row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MapLayer {
+    /// A tile layer, from 0 to 3.
+    ///
+    /// Higher layers are drawn on top of lower layers.
+    Tile(u8),
+
+    /// The shadows cast by walls.
+    Shadow,
+
+    /// The region ids.
+    Region,
+}
+
+impl MapLayer {
+    /// The number of layers.
+    pub const COUNT: u8 = 6;
+
+    /// Get the index of this layer in [`Map::data`].
+    ///
+    /// Returns `None` for tile layers above 3.
+    pub fn index(self) -> Option<u8> {
+        match self {
+            Self::Tile(layer) if layer < 4 => Some(layer),
+            Self::Tile(_) => None,
+            Self::Shadow => Some(4),
+            Self::Region => Some(5),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -152,4 +285,31 @@ mod test {
 
         assert!(map == map_de);
     }
+
+    #[test]
+    fn layers() {
+        let mut map: Map = serde_json::from_str(MAP_1).expect("failed to parse");
+        let width = map.width;
+        let height = map.height;
+        assert!(map.data.len() == (width * height * u32::from(MapLayer::COUNT)) as usize);
+
+        assert!(map.tile(0, 0, 0) == Some(TileId(2816)));
+        assert!(map.tile(width, 0, 0).is_none());
+        assert!(map.tile(0, height, 0).is_none());
+        assert!(map.tile(0, 0, 4).is_none());
+
+        assert!(map.set_tile(1, 2, 3, TileId(5)) == Some(TileId(0)));
+        assert!(map.tile(1, 2, 3) == Some(TileId(5)));
+        assert!(map.data[((3 * height + 2) * width + 1) as usize] == 5);
+
+        assert!(map.region_positions(5).next().is_none());
+        map.set_region(4, 3, 5);
+        map.set_region(2, 7, 5);
+        let positions: Vec<_> = map.region_positions(5).collect();
+        assert!(positions == [(4, 3), (2, 7)], "{positions:?}");
+        assert!(map.data[((5 * height + 3) * width + 4) as usize] == 5);
+
+        assert!(map.set_shadow(0, 0, 0b0101) == Some(0));
+        assert!(map.shadow(0, 0) == Some(0b0101));
+    }
 }
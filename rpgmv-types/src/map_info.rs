@@ -0,0 +1,60 @@
+use crate::ExtraFields;
+use crate::unknown_fields::impl_unknown_fields;
+
+/// An entry in the map tree
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct MapInfo {
+    /// The map id
+    pub id: u32,
+
+    /// Whether the map is expanded in the editor's map tree
+    pub expanded: bool,
+
+    /// The map name
+    pub name: String,
+
+    /// The order of the map in the editor's map tree
+    pub order: u32,
+
+    /// The id of the parent map, or 0 for none
+    #[serde(rename = "parentId")]
+    pub parent_id: u32,
+
+    /// ?
+    #[serde(rename = "scrollX")]
+    pub scroll_x: f64,
+
+    /// ?
+    #[serde(rename = "scrollY")]
+    pub scroll_y: f64,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl_unknown_fields!(MapInfo {});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    // A hand-written sample in the format of a new RPGMaker MV project.
+    // TODO: Replace with a sample from a real project.
+    const MAP_INFOS: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test-data/map-infos/MapInfos.json"
+    ));
+
+    #[test]
+    fn map_infos() {
+        let map_infos: Vec<Option<MapInfo>> =
+            serde_json::from_str(MAP_INFOS).expect("failed to parse");
+        assert!(map_infos[2].is_none());
+
+        let map_infos_ser = serde_json::to_string(&map_infos).expect("failed to serialize");
+        let map_infos_de: Vec<Option<MapInfo>> =
+            serde_json::from_str(&map_infos_ser).expect("failed to parse");
+
+        assert!(map_infos == map_infos_de);
+    }
+}
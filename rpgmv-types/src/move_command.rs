@@ -1,13 +1,64 @@
+use crate::ExtraFields;
+use crate::unknown_fields::impl_unknown_fields;
+
 /// A move command
 #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
-#[serde(deny_unknown_fields)]
 pub struct MoveCommand {
     /// ?
     pub code: u32,
 
     /// ?
+    ///
+    /// The editor leaves this out for some commands without parameters.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parameters: Option<Vec<serde_json::Value>>,
 
     /// ?
-    pub indent: Option<u32>,
+    ///
+    /// This is `None` if missing, and `Some(None)` if null.
+    /// The editor writes a null indent for commands in a Set Movement Route command.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_nullable"
+    )]
+    pub indent: Option<Option<u32>>,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl_unknown_fields!(MoveCommand {});
+
+/// Deserialize a field that may be null, so that it can be told apart from a missing field.
+fn deserialize_nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+{
+    serde::Deserialize::deserialize(deserializer).map(Some)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn missing_and_null_fields() {
+        // The editor writes all of these forms.
+        let inputs = [
+            r#"{"code":0,"parameters":[],"indent":null}"#,
+            r#"{"code":0,"parameters":[]}"#,
+            r#"{"code":15,"parameters":[60],"indent":0}"#,
+            r#"{"code":1,"indent":null}"#,
+            r#"{"code":0}"#,
+        ];
+
+        for input in inputs {
+            let command: MoveCommand = serde_json::from_str(input).expect("failed to parse");
+            let output = serde_json::to_string(&command).expect("failed to serialize");
+            assert!(output == input, "{output} != {input}");
+        }
+    }
 }
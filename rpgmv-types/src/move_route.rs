@@ -1,8 +1,9 @@
+use crate::ExtraFields;
 use crate::MoveCommand;
+use crate::unknown_fields::impl_unknown_fields;
 
 /// A move route
 #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
-#[serde(deny_unknown_fields)]
 pub struct MoveRoute {
     /// ?
     pub list: Vec<MoveCommand>,
@@ -15,4 +16,10 @@ pub struct MoveRoute {
 
     /// ?
     pub wait: bool,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
+
+impl_unknown_fields!(MoveRoute { "list" => list });
@@ -0,0 +1,345 @@
+use crate::Error;
+use crate::MoveCommand;
+use crate::MoveRoute;
+use serde_json::Value;
+
+/// A reader over the parameters of a single command.
+pub(crate) struct ParamReader<'a> {
+    code: u32,
+    parameters: &'a [Value],
+    index: usize,
+}
+
+impl<'a> ParamReader<'a> {
+    /// Make a new param reader.
+    pub(crate) fn new(code: u32, parameters: &'a [Value]) -> Self {
+        Self {
+            code,
+            parameters,
+            index: 0,
+        }
+    }
+
+    /// Check if every parameter has been read.
+    pub(crate) fn is_done(&self) -> bool {
+        self.index >= self.parameters.len()
+    }
+
+    /// Read the next parameter.
+    pub(crate) fn read<T>(&mut self) -> Result<T, Error>
+    where
+        T: Param,
+    {
+        T::read(self)
+    }
+
+    /// Read the next parameter, if there are any left.
+    ///
+    /// This is used for parameters that were added in later engine versions.
+    pub(crate) fn read_optional<T>(&mut self) -> Result<Option<T>, Error>
+    where
+        T: Param,
+    {
+        if self.is_done() {
+            return Ok(None);
+        }
+
+        self.read().map(Some)
+    }
+
+    /// Read the next raw parameter and convert it.
+    pub(crate) fn read_with<T, F>(&mut self, expected: &'static str, func: F) -> Result<T, Error>
+    where
+        F: FnOnce(&Value) -> Option<T>,
+    {
+        let value = self
+            .parameters
+            .get(self.index)
+            .ok_or(Error::MissingParameter {
+                code: self.code,
+                index: self.index,
+            })?;
+        self.index += 1;
+
+        func(value).ok_or_else(|| self.invalid(expected))
+    }
+
+    /// Make an error for the last parameter that was read.
+    pub(crate) fn invalid(&self, expected: &'static str) -> Error {
+        Error::InvalidParameter {
+            code: self.code,
+            index: self.index.saturating_sub(1),
+            expected,
+        }
+    }
+
+    /// Ensure that every parameter was read.
+    pub(crate) fn finish(self) -> Result<(), Error> {
+        if !self.is_done() {
+            return Err(Error::TooManyParameters {
+                code: self.code,
+                len: self.parameters.len(),
+                expected: self.index,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// A type that can be read from and written to command parameters.
+///
+/// Implementations must write exactly what they read,
+/// so that conversions round-trip.
+pub(crate) trait Param: Sized {
+    /// Read this from the parameters.
+    fn read(reader: &mut ParamReader<'_>) -> Result<Self, Error>;
+
+    /// Write this to the parameters.
+    fn write(self, parameters: &mut Vec<Value>);
+}
+
+macro_rules! impl_param_int {
+    ($($ty:ty),*) => {
+        $(
+            impl Param for $ty {
+                fn read(reader: &mut ParamReader<'_>) -> Result<Self, Error> {
+                    reader.read_with(stringify!($ty), |value| {
+                        value.as_i64().and_then(|value| Self::try_from(value).ok())
+                    })
+                }
+
+                fn write(self, parameters: &mut Vec<Value>) {
+                    parameters.push(self.into());
+                }
+            }
+        )*
+    };
+}
+
+impl_param_int!(u8, u32, i32);
+
+impl Param for bool {
+    fn read(reader: &mut ParamReader<'_>) -> Result<Self, Error> {
+        reader.read_with("bool", Value::as_bool)
+    }
+
+    fn write(self, parameters: &mut Vec<Value>) {
+        parameters.push(self.into());
+    }
+}
+
+impl Param for String {
+    fn read(reader: &mut ParamReader<'_>) -> Result<Self, Error> {
+        reader.read_with("string", |value| value.as_str().map(String::from))
+    }
+
+    fn write(self, parameters: &mut Vec<Value>) {
+        parameters.push(self.into());
+    }
+}
+
+/// A string that may be null.
+impl Param for Option<String> {
+    fn read(reader: &mut ParamReader<'_>) -> Result<Self, Error> {
+        reader.read_with("nullable string", |value| match value {
+            Value::Null => Some(None),
+            Value::String(value) => Some(Some(value.clone())),
+            _ => None,
+        })
+    }
+
+    fn write(self, parameters: &mut Vec<Value>) {
+        parameters.push(self.into());
+    }
+}
+
+impl Param for Vec<String> {
+    fn read(reader: &mut ParamReader<'_>) -> Result<Self, Error> {
+        reader.read_with("string array", |value| {
+            value
+                .as_array()?
+                .iter()
+                .map(|value| value.as_str().map(String::from))
+                .collect()
+        })
+    }
+
+    fn write(self, parameters: &mut Vec<Value>) {
+        parameters.push(self.into());
+    }
+}
+
+impl Param for Vec<i32> {
+    fn read(reader: &mut ParamReader<'_>) -> Result<Self, Error> {
+        reader.read_with("i32 array", |value| {
+            value
+                .as_array()?
+                .iter()
+                .map(|value| value.as_i64().and_then(|value| i32::try_from(value).ok()))
+                .collect()
+        })
+    }
+
+    fn write(self, parameters: &mut Vec<Value>) {
+        parameters.push(self.into());
+    }
+}
+
+/// A map of strings, in the order it was written.
+impl Param for Vec<(String, String)> {
+    fn read(reader: &mut ParamReader<'_>) -> Result<Self, Error> {
+        reader.read_with("string map", |value| {
+            value
+                .as_object()?
+                .iter()
+                .map(|(key, value)| Some((key.clone(), value.as_str()?.to_string())))
+                .collect()
+        })
+    }
+
+    fn write(self, parameters: &mut Vec<Value>) {
+        let value = self
+            .into_iter()
+            .map(|(key, value)| (key, Value::String(value)))
+            .collect();
+        parameters.push(Value::Object(value));
+    }
+}
+
+impl Param for MoveRoute {
+    fn read(reader: &mut ParamReader<'_>) -> Result<Self, Error> {
+        reader.read_with("move route", |value| {
+            serde_json::from_value(value.clone()).ok()
+        })
+    }
+
+    fn write(self, parameters: &mut Vec<Value>) {
+        parameters.push(serde_json::to_value(self).expect("failed to serialize move route"));
+    }
+}
+
+impl Param for MoveCommand {
+    fn read(reader: &mut ParamReader<'_>) -> Result<Self, Error> {
+        reader.read_with("move command", |value| {
+            serde_json::from_value(value.clone()).ok()
+        })
+    }
+
+    fn write(self, parameters: &mut Vec<Value>) {
+        parameters.push(serde_json::to_value(self).expect("failed to serialize move command"));
+    }
+}
+
+impl Param for Value {
+    fn read(reader: &mut ParamReader<'_>) -> Result<Self, Error> {
+        reader.read_with("value", |value| Some(value.clone()))
+    }
+
+    fn write(self, parameters: &mut Vec<Value>) {
+        parameters.push(self);
+    }
+}
+
+/// Define an enum of typed command parameters, keyed by command code.
+///
+/// Fields in square brackets after a variant are optional trailing parameters.
+macro_rules! typed_data {
+    (
+        $(#[$enum_docs:meta])*
+        pub enum $enum_name:ident {
+            $(
+                $(#[$docs:meta])*
+                $code:literal => $name:ident {
+                    $(
+                        $(#[$field_docs:meta])*
+                        $field:ident: $field_ty:ty,
+                    )*
+                } $([
+                    $(
+                        $(#[$optional_field_docs:meta])*
+                        $optional_field:ident: $optional_field_ty:ty,
+                    )*
+                ])?,
+            )*
+        }
+    ) => {
+        $(#[$enum_docs])*
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum $enum_name {
+            $(
+                $(#[$docs])*
+                $name {
+                    $(
+                        $(#[$field_docs])*
+                        $field: $field_ty,
+                    )*
+                    $($(
+                        $(#[$optional_field_docs])*
+                        $optional_field: Option<$optional_field_ty>,
+                    )*)?
+                },
+            )*
+        }
+
+        impl $enum_name {
+            /// Get the command code.
+            pub fn code(&self) -> u32 {
+                match self {
+                    $(Self::$name { .. } => $code,)*
+                }
+            }
+
+            /// Parse the parameters of a command with the given code.
+            pub fn from_parameters(
+                code: u32,
+                parameters: &[serde_json::Value],
+            ) -> Result<Self, $crate::Error> {
+                let mut reader = $crate::param::ParamReader::new(code, parameters);
+                let data = match code {
+                    $(
+                        $code => {
+                            $(let $field: $field_ty = reader.read()?;)*
+                            $($(
+                                let $optional_field: Option<$optional_field_ty> =
+                                    reader.read_optional()?;
+                            )*)?
+
+                            Self::$name {
+                                $($field,)*
+                                $($($optional_field,)*)?
+                            }
+                        }
+                    )*
+                    _ => return Err($crate::Error::UnknownCode { code }),
+                };
+                reader.finish()?;
+
+                Ok(data)
+            }
+
+            /// Convert this into the raw parameters of a command.
+            pub fn into_parameters(self) -> Vec<serde_json::Value> {
+                let mut parameters = Vec::new();
+                match self {
+                    $(
+                        Self::$name {
+                            $($field,)*
+                            $($($optional_field,)*)?
+                        } => {
+                            $($crate::param::Param::write($field, &mut parameters);)*
+                            $($(
+                                if let Some($optional_field) = $optional_field {
+                                    $crate::param::Param::write($optional_field, &mut parameters);
+                                }
+                            )*)?
+                        }
+                    )*
+                }
+                parameters
+            }
+        }
+    };
+}
+
+pub(crate) use typed_data;
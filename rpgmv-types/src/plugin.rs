@@ -1,7 +1,8 @@
+use crate::ExtraFields;
+use crate::unknown_fields::impl_unknown_fields;
 use std::collections::HashMap;
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
-#[serde(deny_unknown_fields)]
 pub struct Plugin {
     /// The name of the plugin
     pub name: String,
@@ -15,4 +16,10 @@ pub struct Plugin {
     /// Arguments take the form of a key-value string map.
     /// As an example, the number 5 would be stringifed as "5" before being inserted into this map.
     pub parameters: HashMap<String, String>,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
+
+impl_unknown_fields!(Plugin {});
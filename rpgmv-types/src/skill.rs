@@ -1,5 +1,7 @@
+use crate::ExtraFields;
+use crate::unknown_fields::impl_unknown_fields;
+
 #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct SkillDamage {
     /// ?
     pub critical: bool,
@@ -17,10 +19,15 @@ pub struct SkillDamage {
 
     /// ?
     pub variance: u32,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
+impl_unknown_fields!(SkillDamage {});
+
 #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct SkillEffect {
     /// ?
     pub code: u32,
@@ -34,10 +41,15 @@ pub struct SkillEffect {
 
     /// ?
     pub value2: u32,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
+impl_unknown_fields!(SkillEffect {});
+
 #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Skill {
     /// The skill id
     pub id: u32,
@@ -118,6 +130,12 @@ pub struct Skill {
     /// ?
     ///
     /// This is an MZ-only field.
-    #[serde(rename = "messageType")]
+    #[serde(rename = "messageType", skip_serializing_if = "Option::is_none")]
     pub message_type: Option<u32>,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
+
+impl_unknown_fields!(Skill { "damage" => damage, "effects" => effects });
@@ -1,29 +1,544 @@
-use std::collections::HashMap;
+use super::AudioFile;
+use crate::ExtraFields;
+use crate::unknown_fields::impl_unknown_fields;
+use std::collections::BTreeMap;
 
-/// Note: This struct is incomplete
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+/// Advanced settings
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
 pub struct SystemAdvanced {
-    #[serde(rename = "mainFontFilename")]
-    pub main_font_filename: String,
+    /// ?
+    #[serde(rename = "gameId")]
+    pub game_id: u32,
+
+    /// The screen width
+    #[serde(rename = "screenWidth")]
+    pub screen_width: u16,
+
+    /// The screen height
+    #[serde(rename = "screenHeight")]
+    pub screen_height: u16,
+
+    /// ?
+    #[serde(rename = "uiAreaWidth")]
+    pub ui_area_width: u16,
+
+    /// ?
+    #[serde(rename = "uiAreaHeight")]
+    pub ui_area_height: u16,
+
+    /// The font file name for numbers
+    #[serde(rename = "numberFontFilename")]
+    pub number_font_filename: String,
 
+    /// A css font list, used if the fonts fail to load
+    #[serde(rename = "fallbackFonts")]
+    pub fallback_fonts: String,
+
+    /// The font size
     #[serde(rename = "fontSize")]
     pub font_size: u16,
 
-    #[serde(rename = "screenWidth")]
-    pub screen_width: u16,
+    /// The main font file name
+    #[serde(rename = "mainFontFilename")]
+    pub main_font_filename: String,
+
+    /// ?
+    #[serde(rename = "windowOpacity")]
+    pub window_opacity: u8,
+
+    /// ?
+    ///
+    /// This is not present in older versions.
+    #[serde(rename = "screenScale", skip_serializing_if = "Option::is_none")]
+    pub screen_scale: Option<f64>,
+
+    /// ?
+    ///
+    /// This is not present in older versions.
+    #[serde(rename = "picturesUpperLimit", skip_serializing_if = "Option::is_none")]
+    pub pictures_upper_limit: Option<u32>,
 
-    /// Extra k/v entries
+    /// Fields that are not known to this library
     #[serde(flatten)]
-    pub extra: HashMap<String, serde_json::Value>,
+    pub extra: ExtraFields,
 }
 
-/// Note: This struct is incomplete
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+impl_unknown_fields!(SystemAdvanced {});
+
+/// A vehicle
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct SystemVehicle {
+    /// The music played while riding
+    pub bgm: AudioFile,
+
+    /// ?
+    #[serde(rename = "characterIndex")]
+    pub character_index: u32,
+
+    /// The character image name
+    #[serde(rename = "characterName")]
+    pub character_name: String,
+
+    /// The starting map id
+    #[serde(rename = "startMapId")]
+    pub start_map_id: u32,
+
+    /// The starting x position
+    #[serde(rename = "startX")]
+    pub start_x: u32,
+
+    /// The starting y position
+    #[serde(rename = "startY")]
+    pub start_y: u32,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl_unknown_fields!(SystemVehicle { "bgm" => bgm });
+
+/// An attack motion, by weapon type
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct SystemAttackMotion {
+    /// ?
+    #[serde(rename = "type")]
+    pub kind: u32,
+
+    /// ?
+    #[serde(rename = "weaponImageId")]
+    pub weapon_image_id: u32,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl_unknown_fields!(SystemAttackMotion {});
+
+/// The game terms
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct SystemTerms {
+    /// Basic status terms, like "Level"
+    pub basic: Vec<String>,
+
+    /// Command names, like "Attack"
+    pub commands: Vec<Option<String>>,
+
+    /// Param names, like "Max HP"
+    pub params: Vec<String>,
+
+    /// Messages, by name
+    pub messages: BTreeMap<String, String>,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl_unknown_fields!(SystemTerms {});
+
+/// An actor used for battle tests
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct SystemTestBattler {
+    /// The actor id
+    #[serde(rename = "actorId")]
+    pub actor_id: u32,
+
+    /// The equipment ids, by equipment slot
+    pub equips: Vec<u32>,
+
+    /// The level
+    pub level: u32,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl_unknown_fields!(SystemTestBattler {});
+
+/// Editor settings
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct SystemEditor {
+    /// ?
+    #[serde(rename = "messageWidth1")]
+    pub message_width_1: u32,
+
+    /// ?
+    #[serde(rename = "messageWidth2")]
+    pub message_width_2: u32,
+
+    /// ?
+    #[serde(rename = "jsonFormatLevel")]
+    pub json_format_level: u32,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl_unknown_fields!(SystemEditor {});
+
+/// The title command window
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct SystemTitleCommandWindow {
+    /// ?
+    pub background: u32,
+
+    /// ?
+    #[serde(rename = "offsetX")]
+    pub offset_x: i32,
+
+    /// ?
+    #[serde(rename = "offsetY")]
+    pub offset_y: i32,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl_unknown_fields!(SystemTitleCommandWindow {});
+
+/// The System.json file
+///
+/// MV and MZ share most fields.
+/// Fields only present in MZ are `None` for MV games.
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
 pub struct System {
-    /// This field is MZ only.
+    /// Advanced settings
+    ///
+    /// This is an MZ-only field.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub advanced: Option<SystemAdvanced>,
 
-    /// Extra k/v entries
+    /// The airship
+    pub airship: SystemVehicle,
+
+    /// Armor type names, by id
+    #[serde(rename = "armorTypes")]
+    pub armor_types: Vec<String>,
+
+    /// Attack motions, by weapon type id
+    #[serde(rename = "attackMotions")]
+    pub attack_motions: Vec<SystemAttackMotion>,
+
+    /// The battle music
+    #[serde(rename = "battleBgm")]
+    pub battle_bgm: AudioFile,
+
+    /// ?
+    ///
+    /// This is an MZ-only field.
+    #[serde(rename = "battleSystem", skip_serializing_if = "Option::is_none")]
+    pub battle_system: Option<u32>,
+
+    /// ?
+    #[serde(rename = "battleback1Name")]
+    pub battleback1_name: String,
+
+    /// ?
+    #[serde(rename = "battleback2Name")]
+    pub battleback2_name: String,
+
+    /// ?
+    #[serde(rename = "battlerHue")]
+    pub battler_hue: u32,
+
+    /// ?
+    #[serde(rename = "battlerName")]
+    pub battler_name: String,
+
+    /// The boat
+    pub boat: SystemVehicle,
+
+    /// The currency unit
+    #[serde(rename = "currencyUnit")]
+    pub currency_unit: String,
+
+    /// The music played on defeat
+    #[serde(rename = "defeatMe")]
+    pub defeat_me: AudioFile,
+
+    /// The id of the map open in the editor
+    #[serde(rename = "editMapId")]
+    pub edit_map_id: u32,
+
+    /// Editor settings
+    ///
+    /// This is an MZ-only field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub editor: Option<SystemEditor>,
+
+    /// Element names, by id
+    pub elements: Vec<String>,
+
+    /// Equipment type names, by id
+    #[serde(rename = "equipTypes")]
+    pub equip_types: Vec<String>,
+
+    /// ?
+    ///
+    /// This is an MZ-only field.
+    #[serde(rename = "faceSize", skip_serializing_if = "Option::is_none")]
+    pub face_size: Option<u32>,
+
+    /// The game title
+    #[serde(rename = "gameTitle")]
+    pub game_title: String,
+
+    /// The music played on game over
+    #[serde(rename = "gameoverMe")]
+    pub gameover_me: AudioFile,
+
+    /// ?
+    ///
+    /// This is an MZ-only field.
+    #[serde(rename = "iconSize", skip_serializing_if = "Option::is_none")]
+    pub icon_size: Option<u32>,
+
+    /// ?
+    ///
+    /// This is an MZ-only field.
+    #[serde(rename = "itemCategories", skip_serializing_if = "Option::is_none")]
+    pub item_categories: Option<Vec<bool>>,
+
+    /// The locale
+    pub locale: String,
+
+    /// Skill type ids that count as magic
+    #[serde(rename = "magicSkills")]
+    pub magic_skills: Vec<u32>,
+
+    /// Which menu commands are enabled
+    #[serde(rename = "menuCommands")]
+    pub menu_commands: Vec<bool>,
+
+    /// ?
+    ///
+    /// This is an MZ-only field.
+    #[serde(rename = "optAutosave", skip_serializing_if = "Option::is_none")]
+    pub opt_autosave: Option<bool>,
+
+    /// ?
+    #[serde(rename = "optDisplayTp")]
+    pub opt_display_tp: bool,
+
+    /// ?
+    #[serde(rename = "optDrawTitle")]
+    pub opt_draw_title: bool,
+
+    /// ?
+    #[serde(rename = "optExtraExp")]
+    pub opt_extra_exp: bool,
+
+    /// ?
+    #[serde(rename = "optFloorDeath")]
+    pub opt_floor_death: bool,
+
+    /// ?
+    #[serde(rename = "optFollowers")]
+    pub opt_followers: bool,
+
+    /// ?
+    ///
+    /// This is an MZ-only field.
+    #[serde(rename = "optKeyItemsNumber", skip_serializing_if = "Option::is_none")]
+    pub opt_key_items_number: Option<bool>,
+
+    /// ?
+    ///
+    /// This is an MZ-only field.
+    #[serde(rename = "optMessageSkip", skip_serializing_if = "Option::is_none")]
+    pub opt_message_skip: Option<bool>,
+
+    /// ?
+    #[serde(rename = "optSideView")]
+    pub opt_side_view: bool,
+
+    /// ?
+    #[serde(rename = "optSlipDeath")]
+    pub opt_slip_death: bool,
+
+    /// ?
+    ///
+    /// This is an MZ-only field.
+    #[serde(rename = "optSplashScreen", skip_serializing_if = "Option::is_none")]
+    pub opt_splash_screen: Option<bool>,
+
+    /// ?
+    #[serde(rename = "optTransparent")]
+    pub opt_transparent: bool,
+
+    /// The actor ids of the starting party
+    #[serde(rename = "partyMembers")]
+    pub party_members: Vec<u32>,
+
+    /// The ship
+    pub ship: SystemVehicle,
+
+    /// Skill type names, by id
+    #[serde(rename = "skillTypes")]
+    pub skill_types: Vec<String>,
+
+    /// System sound effects
+    pub sounds: Vec<AudioFile>,
+
+    /// The starting map id
+    #[serde(rename = "startMapId")]
+    pub start_map_id: u32,
+
+    /// The starting x position
+    #[serde(rename = "startX")]
+    pub start_x: u32,
+
+    /// The starting y position
+    #[serde(rename = "startY")]
+    pub start_y: u32,
+
+    /// Switch names, by id
+    pub switches: Vec<String>,
+
+    /// The game terms
+    pub terms: SystemTerms,
+
+    /// The party used for battle tests
+    #[serde(rename = "testBattlers")]
+    pub test_battlers: Vec<SystemTestBattler>,
+
+    /// The troop id used for battle tests
+    #[serde(rename = "testTroopId")]
+    pub test_troop_id: u32,
+
+    /// ?
+    ///
+    /// This is an MZ-only field.
+    #[serde(rename = "tileSize", skip_serializing_if = "Option::is_none")]
+    pub tile_size: Option<u32>,
+
+    /// ?
+    #[serde(rename = "title1Name")]
+    pub title1_name: String,
+
+    /// ?
+    #[serde(rename = "title2Name")]
+    pub title2_name: String,
+
+    /// The title music
+    #[serde(rename = "titleBgm")]
+    pub title_bgm: AudioFile,
+
+    /// ?
+    ///
+    /// This is an MZ-only field.
+    #[serde(rename = "titleCommandWindow", skip_serializing_if = "Option::is_none")]
+    pub title_command_window: Option<SystemTitleCommandWindow>,
+
+    /// Variable names, by id
+    pub variables: Vec<String>,
+
+    /// ?
+    #[serde(rename = "versionId")]
+    pub version_id: u32,
+
+    /// The music played on victory
+    #[serde(rename = "victoryMe")]
+    pub victory_me: AudioFile,
+
+    /// Weapon type names, by id
+    #[serde(rename = "weaponTypes")]
+    pub weapon_types: Vec<String>,
+
+    /// The window tone, as rgb and gray
+    #[serde(rename = "windowTone")]
+    pub window_tone: Vec<i32>,
+
+    /// Whether images are encrypted
+    ///
+    /// This is only present in deployed games.
+    #[serde(rename = "hasEncryptedImages", skip_serializing_if = "Option::is_none")]
+    pub has_encrypted_images: Option<bool>,
+
+    /// Whether audio is encrypted
+    ///
+    /// This is only present in deployed games.
+    #[serde(rename = "hasEncryptedAudio", skip_serializing_if = "Option::is_none")]
+    pub has_encrypted_audio: Option<bool>,
+
+    /// The encryption key, as hex
+    ///
+    /// This is only present in deployed games.
+    #[serde(rename = "encryptionKey", skip_serializing_if = "Option::is_none")]
+    pub encryption_key: Option<String>,
+
+    /// Fields that are not known to this library
     #[serde(flatten)]
-    pub extra: HashMap<String, serde_json::Value>,
+    pub extra: ExtraFields,
+}
+
+impl_unknown_fields!(System {
+    "advanced" => advanced,
+    "airship" => airship,
+    "attackMotions" => attack_motions,
+    "battleBgm" => battle_bgm,
+    "boat" => boat,
+    "defeatMe" => defeat_me,
+    "editor" => editor,
+    "gameoverMe" => gameover_me,
+    "ship" => ship,
+    "sounds" => sounds,
+    "terms" => terms,
+    "testBattlers" => test_battlers,
+    "titleBgm" => title_bgm,
+    "titleCommandWindow" => title_command_window,
+    "victoryMe" => victory_me,
+});
+
+impl System {
+    /// Check if this is from an MZ game.
+    pub fn is_mz(&self) -> bool {
+        self.advanced.is_some()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    // Hand-written samples in the format of deployed RPGMaker MV and MZ projects.
+    const SYSTEM_MV: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test-data/system/System-mv.json"
+    ));
+    const SYSTEM_MZ: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test-data/system/System-mz.json"
+    ));
+
+    #[test]
+    fn system_mv() {
+        let system: System = serde_json::from_str(SYSTEM_MV).expect("failed to parse");
+        assert!(!system.is_mz());
+        assert!(system.switches[1] == "Door Open");
+        assert!(system.has_encrypted_images == Some(true));
+
+        let system_ser = serde_json::to_value(&system).expect("failed to serialize");
+        let system_value: serde_json::Value =
+            serde_json::from_str(SYSTEM_MV).expect("failed to parse");
+
+        assert!(system_ser == system_value);
+    }
+
+    #[test]
+    fn system_mz() {
+        let system: System = serde_json::from_str(SYSTEM_MZ).expect("failed to parse");
+        assert!(system.is_mz());
+        assert!(system.tile_size == Some(48));
+
+        let system_ser = serde_json::to_value(&system).expect("failed to serialize");
+        let system_value: serde_json::Value =
+            serde_json::from_str(SYSTEM_MZ).expect("failed to parse");
+
+        assert!(system_ser == system_value);
+    }
 }
@@ -0,0 +1,402 @@
+/// A tileset image that a tile is drawn from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TileSheet {
+    /// Animated autotiles, like water
+    A1,
+
+    /// Ground autotiles
+    A2,
+
+    /// Building autotiles, like roofs and walls
+    A3,
+
+    /// Wall autotiles
+    A4,
+
+    /// Normal ground tiles
+    A5,
+
+    /// Upper layer tiles
+    B,
+
+    /// Upper layer tiles
+    C,
+
+    /// Upper layer tiles
+    D,
+
+    /// Upper layer tiles
+    E,
+}
+
+impl TileSheet {
+    /// Get the index of this sheet's image in [`Tileset::tileset_names`](crate::Tileset::tileset_names).
+    pub fn index(self) -> usize {
+        match self {
+            Self::A1 => 0,
+            Self::A2 => 1,
+            Self::A3 => 2,
+            Self::A4 => 3,
+            Self::A5 => 4,
+            Self::B => 5,
+            Self::C => 6,
+            Self::D => 7,
+            Self::E => 8,
+        }
+    }
+
+    /// Get the first tile id of this sheet.
+    pub fn first_tile_id(self) -> TileId {
+        match self {
+            Self::A1 => TileId::A1,
+            Self::A2 => TileId::A2,
+            Self::A3 => TileId::A3,
+            Self::A4 => TileId::A4,
+            Self::A5 => TileId::A5,
+            Self::B => TileId::B,
+            Self::C => TileId::C,
+            Self::D => TileId::D,
+            Self::E => TileId::E,
+        }
+    }
+
+    /// Check if this sheet is made of autotiles.
+    pub fn is_autotile(self) -> bool {
+        matches!(self, Self::A1 | Self::A2 | Self::A3 | Self::A4)
+    }
+}
+
+/// A tile id, from a tile layer of [`Map::data`](crate::Map::data).
+///
+/// This follows the layout of `Tilemap` in `rpg_core.js`.
+/// Autotiles are stored as a kind and a shape,
+/// where the shape picks which parts of the autotile are drawn based on its neighbors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct TileId(pub i32);
+
+impl TileId {
+    /// The first tile of the B sheet.
+    ///
+    /// Tile 0 is empty.
+    pub const B: Self = Self(0);
+
+    /// The first tile of the C sheet.
+    pub const C: Self = Self(256);
+
+    /// The first tile of the D sheet.
+    pub const D: Self = Self(512);
+
+    /// The first tile of the E sheet.
+    pub const E: Self = Self(768);
+
+    /// The first tile of the A5 sheet.
+    pub const A5: Self = Self(1536);
+
+    /// The first tile of the A1 sheet.
+    pub const A1: Self = Self(2048);
+
+    /// The first tile of the A2 sheet.
+    pub const A2: Self = Self(2816);
+
+    /// The first tile of the A3 sheet.
+    pub const A3: Self = Self(4352);
+
+    /// The first tile of the A4 sheet.
+    pub const A4: Self = Self(5888);
+
+    /// One past the last valid tile id.
+    pub const MAX: Self = Self(8192);
+
+    /// The number of shapes of each autotile kind.
+    pub const AUTOTILE_SHAPES: i32 = 48;
+
+    /// Make a tile id from an autotile kind and shape.
+    pub fn from_autotile(kind: i32, shape: i32) -> Self {
+        Self(Self::A1.0 + kind * Self::AUTOTILE_SHAPES + shape)
+    }
+
+    /// Check if this tile is drawn.
+    ///
+    /// This is false for the empty tile 0, and for invalid tile ids.
+    pub fn is_visible(self) -> bool {
+        self.0 > 0 && self.0 < Self::MAX.0
+    }
+
+    /// Get the sheet that this tile is drawn from.
+    ///
+    /// Returns `None` for invalid tile ids.
+    /// The empty tile 0 is considered part of the B sheet.
+    pub fn sheet(self) -> Option<TileSheet> {
+        let id = self.0;
+        let sheet = if id < Self::B.0 {
+            return None;
+        } else if id < Self::C.0 {
+            TileSheet::B
+        } else if id < Self::D.0 {
+            TileSheet::C
+        } else if id < Self::E.0 {
+            TileSheet::D
+        } else if id < Self::A5.0 {
+            TileSheet::E
+        } else if id < Self::A1.0 {
+            TileSheet::A5
+        } else if id < Self::A2.0 {
+            TileSheet::A1
+        } else if id < Self::A3.0 {
+            TileSheet::A2
+        } else if id < Self::A4.0 {
+            TileSheet::A3
+        } else if id < Self::MAX.0 {
+            TileSheet::A4
+        } else {
+            return None;
+        };
+
+        Some(sheet)
+    }
+
+    /// Check if this tile is an autotile.
+    pub fn is_autotile(self) -> bool {
+        self.sheet().is_some_and(TileSheet::is_autotile)
+    }
+
+    /// Get the autotile kind, if this is an autotile.
+    ///
+    /// Kinds are numbered across all autotile sheets, starting with A1.
+    pub fn autotile_kind(self) -> Option<i32> {
+        if !self.is_autotile() {
+            return None;
+        }
+
+        Some((self.0 - Self::A1.0) / Self::AUTOTILE_SHAPES)
+    }
+
+    /// Get the autotile shape, if this is an autotile.
+    pub fn autotile_shape(self) -> Option<i32> {
+        if !self.is_autotile() {
+            return None;
+        }
+
+        Some((self.0 - Self::A1.0) % Self::AUTOTILE_SHAPES)
+    }
+
+    /// Get the index of this tile within its sheet.
+    ///
+    /// For autotiles, this is the autotile kind within the sheet.
+    /// Otherwise, this is the tile index, counting from the top left.
+    pub fn sheet_index(self) -> Option<i32> {
+        let sheet = self.sheet()?;
+        let offset = self.0 - sheet.first_tile_id().0;
+        if sheet.is_autotile() {
+            Some(offset / Self::AUTOTILE_SHAPES)
+        } else {
+            Some(offset)
+        }
+    }
+
+    /// Check if this is a water tile on the A1 sheet.
+    ///
+    /// This includes waterfalls, but not the decorations in the A1 sheet.
+    pub fn is_water(self) -> bool {
+        self.sheet() == Some(TileSheet::A1)
+            && !(self.0 >= Self::A1.0 + 96 && self.0 < Self::A1.0 + 192)
+    }
+
+    /// Check if this is a waterfall tile on the A1 sheet.
+    pub fn is_waterfall(self) -> bool {
+        self.0 >= Self::A1.0 + 192
+            && self.sheet() == Some(TileSheet::A1)
+            && self.is_waterfall_type_autotile()
+    }
+
+    /// Check if this tile is on the ground, which is on the A1, A2, or A5 sheet.
+    pub fn is_ground(self) -> bool {
+        matches!(
+            self.sheet(),
+            Some(TileSheet::A1 | TileSheet::A2 | TileSheet::A5)
+        )
+    }
+
+    /// Check if this tile casts shadows, which is on the A3 or A4 sheet.
+    pub fn is_shadowing(self) -> bool {
+        matches!(self.sheet(), Some(TileSheet::A3 | TileSheet::A4))
+    }
+
+    /// Check if this is a roof tile on the A3 sheet.
+    pub fn is_roof(self) -> bool {
+        self.sheet() == Some(TileSheet::A3)
+            && self.autotile_kind().is_some_and(|kind| kind % 16 < 8)
+    }
+
+    /// Check if this is the top of a wall on the A4 sheet.
+    pub fn is_wall_top(self) -> bool {
+        self.sheet() == Some(TileSheet::A4)
+            && self.autotile_kind().is_some_and(|kind| kind % 16 < 8)
+    }
+
+    /// Check if this is the side of a wall on the A3 or A4 sheet.
+    pub fn is_wall_side(self) -> bool {
+        self.is_shadowing() && self.autotile_kind().is_some_and(|kind| kind % 16 >= 8)
+    }
+
+    /// Check if this is a wall tile.
+    pub fn is_wall(self) -> bool {
+        self.is_wall_top() || self.is_wall_side()
+    }
+
+    /// Check if this autotile is drawn like a floor, with 47 shapes.
+    pub fn is_floor_type_autotile(self) -> bool {
+        (self.sheet() == Some(TileSheet::A1) && !self.is_waterfall_type_autotile())
+            || self.sheet() == Some(TileSheet::A2)
+            || self.is_wall_top()
+    }
+
+    /// Check if this autotile is drawn like a wall, with 16 shapes.
+    pub fn is_wall_type_autotile(self) -> bool {
+        self.is_roof() || self.is_wall_side()
+    }
+
+    /// Check if this autotile is drawn like a waterfall, with 4 shapes.
+    pub fn is_waterfall_type_autotile(self) -> bool {
+        self.autotile_kind()
+            .is_some_and(|kind| kind >= 4 && kind % 2 == 1 && self.sheet() == Some(TileSheet::A1))
+    }
+}
+
+impl From<i32> for TileId {
+    fn from(id: i32) -> Self {
+        Self(id)
+    }
+}
+
+impl From<TileId> for i32 {
+    fn from(id: TileId) -> Self {
+        id.0
+    }
+}
+
+/// The flags of a tile, from [`Tileset::flags`](crate::Tileset::flags).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct TileFlags(pub u16);
+
+impl TileFlags {
+    /// Check if this tile blocks movement in the given direction.
+    ///
+    /// Directions are numbered like a numpad, so 2 is down, 4 is left, 6 is right, and 8 is up.
+    /// Other directions are never blocked.
+    pub fn blocks(self, direction: u8) -> bool {
+        let bit = match direction {
+            2 => 0x01,
+            4 => 0x02,
+            6 => 0x04,
+            8 => 0x08,
+            _ => return false,
+        };
+
+        self.0 & bit != 0
+    }
+
+    /// Check if this tile is drawn above characters, which is the star in the editor.
+    ///
+    /// These tiles do not affect passability.
+    pub fn is_star(self) -> bool {
+        self.0 & 0x10 != 0
+    }
+
+    /// Check if this tile is a ladder.
+    pub fn is_ladder(self) -> bool {
+        self.0 & 0x20 != 0
+    }
+
+    /// Check if this tile is a bush, which hides the bottom of characters.
+    pub fn is_bush(self) -> bool {
+        self.0 & 0x40 != 0
+    }
+
+    /// Check if this tile is a counter, which can be talked over.
+    pub fn is_counter(self) -> bool {
+        self.0 & 0x80 != 0
+    }
+
+    /// Check if this tile damages the party when walked on.
+    pub fn is_damage_floor(self) -> bool {
+        self.0 & 0x100 != 0
+    }
+
+    /// Check if a boat can not pass this tile.
+    pub fn is_boat_impassable(self) -> bool {
+        self.0 & 0x200 != 0
+    }
+
+    /// Check if a ship can not pass this tile.
+    pub fn is_ship_impassable(self) -> bool {
+        self.0 & 0x400 != 0
+    }
+
+    /// Check if an airship can not land on this tile.
+    pub fn is_airship_land_impassable(self) -> bool {
+        self.0 & 0x800 != 0
+    }
+
+    /// Get the terrain tag, from 0 to 7.
+    pub fn terrain_tag(self) -> u8 {
+        (self.0 >> 12) as u8
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn decode() {
+        let tile = TileId(2816);
+        assert!(tile.sheet() == Some(TileSheet::A2));
+        assert!(tile.is_autotile());
+        assert!(tile.autotile_kind() == Some(16));
+        assert!(tile.autotile_shape() == Some(0));
+        assert!(tile.sheet_index() == Some(0));
+        assert!(tile.is_ground());
+        assert!(tile.is_floor_type_autotile());
+        assert!(TileId::from_autotile(16, 0) == tile);
+
+        let tile = TileId::from_autotile(5, 3);
+        assert!(tile.sheet() == Some(TileSheet::A1));
+        assert!(tile.is_waterfall());
+        assert!(tile.is_water());
+        assert!(tile.is_waterfall_type_autotile());
+        assert!(!tile.is_floor_type_autotile());
+
+        let tile = TileId(TileId::A3.0 + 8 * 48 + 5);
+        assert!(tile.is_wall_side());
+        assert!(tile.is_wall_type_autotile());
+        assert!(tile.sheet_index() == Some(8));
+
+        let tile = TileId(TileId::A4.0);
+        assert!(tile.is_wall_top());
+        assert!(tile.is_floor_type_autotile());
+
+        let tile = TileId(TileId::C.0 + 17);
+        assert!(tile.sheet() == Some(TileSheet::C));
+        assert!(tile.sheet().map(TileSheet::index) == Some(6));
+        assert!(!tile.is_autotile());
+        assert!(tile.autotile_kind().is_none());
+        assert!(tile.sheet_index() == Some(17));
+
+        assert!(!TileId(0).is_visible());
+        assert!(TileId(-1).sheet().is_none());
+        assert!(TileId::MAX.sheet().is_none());
+    }
+
+    #[test]
+    fn flags() {
+        let flags = TileFlags(0x5000 | 0x40 | 0x0A);
+        assert!(flags.blocks(4));
+        assert!(flags.blocks(8));
+        assert!(!flags.blocks(2));
+        assert!(!flags.blocks(5));
+        assert!(flags.is_bush());
+        assert!(!flags.is_star());
+        assert!(flags.terrain_tag() == 5);
+    }
+}
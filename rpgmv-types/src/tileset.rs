@@ -0,0 +1,83 @@
+use crate::ExtraFields;
+use crate::TileFlags;
+use crate::TileId;
+use crate::unknown_fields::impl_unknown_fields;
+
+/// A tileset
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct Tileset {
+    /// The tileset id
+    pub id: u32,
+
+    /// The flags of each tile, like passability
+    pub flags: Vec<u16>,
+
+    /// ?
+    pub mode: u32,
+
+    /// The tileset name
+    pub name: String,
+
+    /// ?
+    pub note: String,
+
+    /// The image names of the A1 to A5, B, C, D, and E tilesets
+    #[serde(rename = "tilesetNames")]
+    pub tileset_names: Vec<String>,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl_unknown_fields!(Tileset {});
+
+impl Tileset {
+    /// Get the flags of a tile.
+    pub fn tile_flags(&self, tile: TileId) -> Option<TileFlags> {
+        let index = usize::try_from(tile.0).ok()?;
+        self.flags.get(index).copied().map(TileFlags)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    // A hand-written sample in the format of a new RPGMaker MV project.
+    // TODO: Replace with a sample from a real project.
+    const TILESETS: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test-data/tilesets/Tilesets.json"
+    ));
+
+    #[test]
+    fn tilesets() {
+        let tilesets: Vec<Option<Tileset>> =
+            serde_json::from_str(TILESETS).expect("failed to parse");
+        assert!(
+            tilesets[1]
+                .as_ref()
+                .expect("missing tileset")
+                .tileset_names
+                .len()
+                == 9
+        );
+
+        let tilesets_ser = serde_json::to_string(&tilesets).expect("failed to serialize");
+        let tilesets_de: Vec<Option<Tileset>> =
+            serde_json::from_str(&tilesets_ser).expect("failed to parse");
+
+        assert!(tilesets == tilesets_de);
+
+        let tileset = tilesets[1].as_ref().expect("missing tileset");
+        let flags = tileset.tile_flags(TileId(7)).expect("missing flags");
+        assert!(flags.is_boat_impassable() && flags.is_ship_impassable());
+        let flags = tileset.tile_flags(TileId::A2).expect("missing flags");
+        assert!(
+            [2, 4, 6, 8]
+                .iter()
+                .all(|direction| flags.blocks(*direction))
+        );
+        assert!(tileset.tile_flags(TileId(-1)).is_none());
+    }
+}
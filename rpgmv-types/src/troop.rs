@@ -1,9 +1,10 @@
 use super::TroopMember;
 use super::TroopPage;
+use crate::ExtraFields;
+use crate::unknown_fields::impl_unknown_fields;
 
 /// A troop
 #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Troop {
     /// The troop id
     pub id: u32,
@@ -16,4 +17,10 @@ pub struct Troop {
 
     /// Event pages
     pub pages: Vec<TroopPage>,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
+
+impl_unknown_fields!(Troop { "members" => members, "pages" => pages });
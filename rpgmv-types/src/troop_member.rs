@@ -1,6 +1,8 @@
+use crate::ExtraFields;
+use crate::unknown_fields::impl_unknown_fields;
+
 /// A troop member
 #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct TroopMember {
     /// The enemy id?
     #[serde(rename = "enemyId")]
@@ -14,4 +16,10 @@ pub struct TroopMember {
 
     /// Whether this is hidden?
     pub hidden: bool,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
+
+impl_unknown_fields!(TroopMember {});
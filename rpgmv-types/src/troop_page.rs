@@ -1,9 +1,10 @@
 use super::EventCommand;
 use super::TroopPageCondition;
+use crate::ExtraFields;
+use crate::unknown_fields::impl_unknown_fields;
 
 /// A troop page
 #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct TroopPage {
     /// Troop page conditions
     pub conditions: TroopPageCondition,
@@ -13,4 +14,10 @@ pub struct TroopPage {
 
     /// ?
     pub span: u32,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
+
+impl_unknown_fields!(TroopPage { "conditions" => conditions, "list" => list });
@@ -1,6 +1,8 @@
+use crate::ExtraFields;
+use crate::unknown_fields::impl_unknown_fields;
+
 /// A troop page condition
 #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct TroopPageCondition {
     #[serde(rename = "actorHp")]
     pub actor_hp: u32,
@@ -37,4 +39,10 @@ pub struct TroopPageCondition {
 
     #[serde(rename = "turnValid")]
     pub turn_valid: bool,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
+
+impl_unknown_fields!(TroopPageCondition {});
@@ -0,0 +1,1032 @@
+mod command_audio_file;
+mod condition;
+mod operand;
+mod variable_operand;
+
+pub use self::command_audio_file::CommandAudioFile;
+pub use self::condition::ActorCheck;
+pub use self::condition::Condition;
+pub use self::condition::EnemyCheck;
+pub use self::operand::Operand;
+pub use self::variable_operand::VariableOperand;
+use crate::Error;
+use crate::EventCommand;
+use crate::ExtraFields;
+use crate::MoveCommand;
+use crate::MoveRoute;
+use crate::param::typed_data;
+use serde_json::Value;
+
+typed_data! {
+    /// The typed parameters of an event command.
+    ///
+    /// Some trailing fields were added in later engine versions,
+    /// and are `None` for older games.
+    /// If one of these is `None`, all of the ones after it must be `None` too.
+    pub enum CommandData {
+        /// Does nothing.
+        ///
+        /// This ends every command list.
+        0 => Nop {},
+
+        /// Show a text box.
+        ///
+        /// The lines follow as [`CommandData::TextData`] commands.
+        101 => ShowText {
+            face_name: String,
+            face_index: u32,
+            background: u32,
+            position_type: u32,
+        } [
+            /// This is only for MZ games.
+            speaker_name: String,
+        ],
+
+        /// Show choices.
+        ///
+        /// Each choice is handled by a [`CommandData::When`] command.
+        102 => ShowChoices {
+            choices: Vec<String>,
+            cancel_type: i32,
+        } [
+            default_type: i32,
+            position_type: u32,
+            background: u32,
+        ],
+
+        /// Ask the player to input a number.
+        103 => InputNumber {
+            variable_id: u32,
+            max_digits: u32,
+        },
+
+        /// Ask the player to select an item.
+        104 => SelectItem {
+            variable_id: u32,
+        } [
+            item_type: u32,
+        ],
+
+        /// Show scrolling text.
+        ///
+        /// The lines follow as [`CommandData::ShowScrollingTextExtra`] commands.
+        105 => ShowScrollingText {
+            speed: u32,
+            no_fast: bool,
+        },
+
+        /// A comment.
+        ///
+        /// Extra lines follow as [`CommandData::CommentExtra`] commands.
+        108 => Comment {
+            line: String,
+        },
+
+        /// Skip to the next command at the same indent.
+        ///
+        /// This is only for MZ games.
+        109 => Skip {},
+
+        /// Run the following commands if a condition is true.
+        111 => ConditionalBranch {
+            condition: Condition,
+        },
+
+        /// Start a loop.
+        112 => Loop {},
+
+        /// Break out of a loop.
+        113 => BreakLoop {},
+
+        /// Stop running the current event.
+        115 => ExitEventProcessing {},
+
+        /// Run a common event.
+        117 => CommonEvent {
+            id: u32,
+        },
+
+        /// A label.
+        118 => Label {
+            name: String,
+        },
+
+        /// Jump to a label.
+        119 => JumpToLabel {
+            name: String,
+        },
+
+        /// Set a range of switches.
+        121 => ControlSwitches {
+            start_id: u32,
+            end_id: u32,
+            /// 0 is on, 1 is off.
+            value: u32,
+        },
+
+        /// Operate on a range of variables.
+        122 => ControlVariables {
+            start_id: u32,
+            end_id: u32,
+            /// One of set, add, sub, mul, div, mod.
+            operation: u32,
+            operand: VariableOperand,
+        },
+
+        /// Set a self switch of the current event.
+        123 => ControlSelfSwitch {
+            key: String,
+            /// 0 is on, 1 is off.
+            value: u32,
+        },
+
+        /// Start or stop the timer.
+        124 => ControlTimer {
+            /// 0 is start, 1 is stop.
+            operation: u32,
+        } [
+            seconds: u32,
+        ],
+
+        /// Change the gold of the party.
+        125 => ChangeGold {
+            /// 0 is increase, 1 is decrease.
+            operation: u32,
+            value: Operand,
+        },
+
+        /// Change the items of the party.
+        126 => ChangeItems {
+            item_id: u32,
+            /// 0 is increase, 1 is decrease.
+            operation: u32,
+            value: Operand,
+        },
+
+        /// Change the weapons of the party.
+        127 => ChangeWeapons {
+            weapon_id: u32,
+            /// 0 is increase, 1 is decrease.
+            operation: u32,
+            value: Operand,
+        } [
+            include_equipped: bool,
+        ],
+
+        /// Change the armors of the party.
+        128 => ChangeArmors {
+            armor_id: u32,
+            /// 0 is increase, 1 is decrease.
+            operation: u32,
+            value: Operand,
+        } [
+            include_equipped: bool,
+        ],
+
+        /// Add or remove a party member.
+        129 => ChangePartyMember {
+            actor_id: u32,
+            /// 0 is add, 1 is remove.
+            operation: u32,
+            initialize: bool,
+        },
+
+        /// Change the battle bgm.
+        132 => ChangeBattleBgm {
+            audio: CommandAudioFile,
+        },
+
+        /// Change the victory me.
+        133 => ChangeVictoryMe {
+            audio: CommandAudioFile,
+        },
+
+        /// Enable or disable saving.
+        134 => ChangeSaveAccess {
+            /// 0 is disable, 1 is enable.
+            value: u32,
+        },
+
+        /// Enable or disable the menu.
+        135 => ChangeMenuAccess {
+            /// 0 is disable, 1 is enable.
+            value: u32,
+        },
+
+        /// Enable or disable encounters.
+        136 => ChangeEncounter {
+            /// 0 is disable, 1 is enable.
+            value: u32,
+        },
+
+        /// Enable or disable changing the formation.
+        137 => ChangeFormationAccess {
+            /// 0 is disable, 1 is enable.
+            value: u32,
+        },
+
+        /// Change the window color.
+        138 => ChangeWindowColor {
+            tone: Vec<i32>,
+        },
+
+        /// Change the defeat me.
+        139 => ChangeDefeatMe {
+            audio: CommandAudioFile,
+        },
+
+        /// Change the bgm of a vehicle.
+        140 => ChangeVehicleBgm {
+            vehicle_id: u32,
+            audio: CommandAudioFile,
+        },
+
+        /// Transfer the player to a location.
+        201 => TransferPlayer {
+            /// 0 is direct, 1 is from variables.
+            designation: u32,
+            map_id: u32,
+            x: u32,
+            y: u32,
+            direction: u32,
+            fade_type: u32,
+        },
+
+        /// Set the location of a vehicle.
+        202 => SetVehicleLocation {
+            vehicle_id: u32,
+            /// 0 is direct, 1 is from variables.
+            designation: u32,
+            map_id: u32,
+            x: u32,
+            y: u32,
+        },
+
+        /// Set the location of an event.
+        203 => SetEventLocation {
+            character_id: i32,
+            /// 0 is direct, 1 is from variables, 2 is exchange with another event.
+            designation: u32,
+            x: i32,
+            y: i32,
+            direction: u32,
+        },
+
+        /// Scroll the map.
+        204 => ScrollMap {
+            direction: u32,
+            distance: u32,
+            speed: u32,
+        } [
+            wait: bool,
+        ],
+
+        /// Set the movement route of a character.
+        ///
+        /// The route commands follow as [`CommandData::SetMovementRouteExtra`] commands,
+        /// which are only used by the editor.
+        205 => SetMovementRoute {
+            character_id: i32,
+            /// The commands of this can be converted into [`crate::TypedMoveCommand`]s.
+            route: MoveRoute,
+        },
+
+        /// Get on or off a vehicle.
+        206 => GetOnOffVehicle {},
+
+        /// Change the transparency of the player.
+        211 => ChangeTransparency {
+            /// 0 is on, 1 is off.
+            value: u32,
+        },
+
+        /// Show an animation on a character.
+        212 => ShowAnimation {
+            character_id: i32,
+            animation_id: u32,
+            wait: bool,
+        },
+
+        /// Show a balloon icon on a character.
+        213 => ShowBalloonIcon {
+            character_id: i32,
+            balloon_id: u32,
+            wait: bool,
+        },
+
+        /// Erase the current event.
+        214 => EraseEvent {},
+
+        /// Show or hide the followers of the player.
+        216 => ChangePlayerFollowers {
+            /// 0 is on, 1 is off.
+            value: u32,
+        },
+
+        /// Gather the followers of the player.
+        217 => GatherFollowers {},
+
+        /// Fade out the screen.
+        221 => FadeoutScreen {},
+
+        /// Fade in the screen.
+        222 => FadeinScreen {},
+
+        /// Tint the screen.
+        223 => TintScreen {
+            tone: Vec<i32>,
+            duration: u32,
+            wait: bool,
+        },
+
+        /// Flash the screen.
+        224 => FlashScreen {
+            color: Vec<i32>,
+            duration: u32,
+            wait: bool,
+        },
+
+        /// Shake the screen.
+        225 => ShakeScreen {
+            power: u32,
+            speed: u32,
+            duration: u32,
+            wait: bool,
+        },
+
+        /// Wait for some frames.
+        230 => Wait {
+            duration: u32,
+        },
+
+        /// Show a picture.
+        231 => ShowPicture {
+            picture_id: u32,
+            picture_name: String,
+            origin: u32,
+            /// 0 is direct, 1 is from variables.
+            designation: u32,
+            x: i32,
+            y: i32,
+            scale_x: i32,
+            scale_y: i32,
+            opacity: u32,
+            blend_mode: u32,
+        },
+
+        /// Move a picture.
+        232 => MovePicture {
+            picture_id: u32,
+            /// This is not used by the engine.
+            unused: Value,
+            origin: u32,
+            /// 0 is direct, 1 is from variables.
+            designation: u32,
+            x: i32,
+            y: i32,
+            scale_x: i32,
+            scale_y: i32,
+            opacity: u32,
+            blend_mode: u32,
+            duration: u32,
+            wait: bool,
+        } [
+            /// This is only for MZ games.
+            easing_type: u32,
+        ],
+
+        /// Rotate a picture.
+        233 => RotatePicture {
+            picture_id: u32,
+            speed: i32,
+        },
+
+        /// Tint a picture.
+        234 => TintPicture {
+            picture_id: u32,
+            tone: Vec<i32>,
+            duration: u32,
+            wait: bool,
+        },
+
+        /// Erase a picture.
+        235 => ErasePicture {
+            picture_id: u32,
+        },
+
+        /// Set the weather.
+        236 => SetWeatherEffect {
+            kind: String,
+            power: u32,
+            duration: u32,
+            wait: bool,
+        },
+
+        /// Play a bgm.
+        241 => PlayBgm {
+            audio: CommandAudioFile,
+        },
+
+        /// Fade out the bgm.
+        242 => FadeoutBgm {
+            duration: u32,
+        },
+
+        /// Save the bgm.
+        243 => SaveBgm {},
+
+        /// Resume the saved bgm.
+        244 => ResumeBgm {},
+
+        /// Play a bgs.
+        245 => PlayBgs {
+            audio: CommandAudioFile,
+        },
+
+        /// Fade out the bgs.
+        246 => FadeoutBgs {
+            duration: u32,
+        },
+
+        /// Play a me.
+        249 => PlayMe {
+            audio: CommandAudioFile,
+        },
+
+        /// Play a se.
+        250 => PlaySe {
+            audio: CommandAudioFile,
+        },
+
+        /// Stop all se.
+        251 => StopSe {},
+
+        /// Play a movie.
+        261 => PlayMovie {
+            name: String,
+        },
+
+        /// Enable or disable showing the map name.
+        281 => ChangeMapNameDisplay {
+            /// 0 is on, 1 is off.
+            value: u32,
+        },
+
+        /// Change the tileset.
+        282 => ChangeTileset {
+            tileset_id: u32,
+        },
+
+        /// Change the battle background.
+        283 => ChangeBattleBack {
+            battleback1_name: String,
+            battleback2_name: String,
+        },
+
+        /// Change the parallax background.
+        284 => ChangeParallax {
+            name: String,
+            loop_x: bool,
+            loop_y: bool,
+            sx: i32,
+            sy: i32,
+        },
+
+        /// Get info about a location on the map.
+        285 => GetLocationInfo {
+            variable_id: u32,
+            info_type: u32,
+            /// 0 is direct, 1 is from variables, 2 is from a character.
+            designation: u32,
+            x: i32,
+            y: i32,
+        },
+
+        /// Start a battle.
+        301 => BattleProcessing {
+            /// 0 is direct, 1 is from a variable, 2 is a random encounter.
+            designation: u32,
+            troop_id: u32,
+            can_escape: bool,
+            can_lose: bool,
+        },
+
+        /// Open a shop.
+        ///
+        /// Extra goods follow as [`CommandData::ShopProcessingExtra`] commands.
+        302 => ShopProcessing {
+            /// 0 is item, 1 is weapon, 2 is armor.
+            kind: u32,
+            id: u32,
+            /// 0 is the standard price, 1 is a custom price.
+            price_type: u32,
+            price: u32,
+            purchase_only: bool,
+        },
+
+        /// Ask the player to input the name of an actor.
+        303 => NameInputProcessing {
+            actor_id: u32,
+            max_len: u32,
+        },
+
+        /// Change the hp of an actor.
+        311 => ChangeHp {
+            /// An actor id of 0 is the entire party.
+            actor: Operand,
+            /// 0 is increase, 1 is decrease.
+            operation: u32,
+            value: Operand,
+            allow_death: bool,
+        },
+
+        /// Change the mp of an actor.
+        312 => ChangeMp {
+            /// An actor id of 0 is the entire party.
+            actor: Operand,
+            /// 0 is increase, 1 is decrease.
+            operation: u32,
+            value: Operand,
+        },
+
+        /// Add or remove a state from an actor.
+        313 => ChangeState {
+            /// An actor id of 0 is the entire party.
+            actor: Operand,
+            /// 0 is add, 1 is remove.
+            operation: u32,
+            state_id: u32,
+        },
+
+        /// Fully recover an actor.
+        314 => RecoverAll {
+            /// An actor id of 0 is the entire party.
+            actor: Operand,
+        },
+
+        /// Change the exp of an actor.
+        315 => ChangeExp {
+            /// An actor id of 0 is the entire party.
+            actor: Operand,
+            /// 0 is increase, 1 is decrease.
+            operation: u32,
+            value: Operand,
+            show_level_up: bool,
+        },
+
+        /// Change the level of an actor.
+        316 => ChangeLevel {
+            /// An actor id of 0 is the entire party.
+            actor: Operand,
+            /// 0 is increase, 1 is decrease.
+            operation: u32,
+            value: Operand,
+            show_level_up: bool,
+        },
+
+        /// Change a parameter of an actor.
+        317 => ChangeParameter {
+            /// An actor id of 0 is the entire party.
+            actor: Operand,
+            param_id: u32,
+            /// 0 is increase, 1 is decrease.
+            operation: u32,
+            value: Operand,
+        },
+
+        /// Teach or forget a skill for an actor.
+        318 => ChangeSkill {
+            /// An actor id of 0 is the entire party.
+            actor: Operand,
+            /// 0 is learn, 1 is forget.
+            operation: u32,
+            skill_id: u32,
+        },
+
+        /// Change the equipment of an actor.
+        319 => ChangeEquipment {
+            actor_id: u32,
+            equip_type_id: u32,
+            item_id: u32,
+        },
+
+        /// Change the name of an actor.
+        320 => ChangeName {
+            actor_id: u32,
+            name: String,
+        },
+
+        /// Change the class of an actor.
+        321 => ChangeClass {
+            actor_id: u32,
+            class_id: u32,
+        } [
+            keep_exp: bool,
+        ],
+
+        /// Change the images of an actor.
+        322 => ChangeActorImages {
+            actor_id: u32,
+            character_name: String,
+            character_index: u32,
+            face_name: String,
+            face_index: u32,
+            battler_name: String,
+        },
+
+        /// Change the image of a vehicle.
+        323 => ChangeVehicleImage {
+            vehicle_id: u32,
+            character_name: String,
+            character_index: u32,
+        },
+
+        /// Change the nickname of an actor.
+        324 => ChangeNickname {
+            actor_id: u32,
+            nickname: String,
+        },
+
+        /// Change the profile of an actor.
+        325 => ChangeProfile {
+            actor_id: u32,
+            profile: String,
+        },
+
+        /// Change the tp of an actor.
+        ///
+        /// This is only for MZ games.
+        326 => ChangeTp {
+            /// An actor id of 0 is the entire party.
+            actor: Operand,
+            /// 0 is increase, 1 is decrease.
+            operation: u32,
+            value: Operand,
+        },
+
+        /// Change the hp of an enemy.
+        331 => ChangeEnemyHp {
+            /// -1 is the entire troop.
+            enemy_index: i32,
+            /// 0 is increase, 1 is decrease.
+            operation: u32,
+            value: Operand,
+            allow_death: bool,
+        },
+
+        /// Change the mp of an enemy.
+        332 => ChangeEnemyMp {
+            /// -1 is the entire troop.
+            enemy_index: i32,
+            /// 0 is increase, 1 is decrease.
+            operation: u32,
+            value: Operand,
+        },
+
+        /// Add or remove a state from an enemy.
+        333 => ChangeEnemyState {
+            /// -1 is the entire troop.
+            enemy_index: i32,
+            /// 0 is add, 1 is remove.
+            operation: u32,
+            state_id: u32,
+        },
+
+        /// Fully recover an enemy.
+        334 => EnemyRecoverAll {
+            /// -1 is the entire troop.
+            enemy_index: i32,
+        },
+
+        /// Make a hidden enemy appear.
+        335 => EnemyAppear {
+            enemy_index: i32,
+        },
+
+        /// Transform an enemy into another enemy.
+        336 => EnemyTransform {
+            enemy_index: i32,
+            enemy_id: u32,
+        },
+
+        /// Show a battle animation on an enemy.
+        337 => ShowBattleAnimation {
+            enemy_index: i32,
+            animation_id: u32,
+        } [
+            all_enemies: bool,
+        ],
+
+        /// Force a battler to use a skill.
+        339 => ForceAction {
+            /// 0 is an enemy, 1 is an actor.
+            subject_type: u32,
+            subject_id: u32,
+            skill_id: u32,
+            /// -2 is the last target, -1 is random.
+            target_index: i32,
+        },
+
+        /// Abort the battle.
+        340 => AbortBattle {},
+
+        /// Change the tp of an enemy.
+        ///
+        /// This is only for MZ games.
+        342 => ChangeEnemyTp {
+            /// -1 is the entire troop.
+            enemy_index: i32,
+            /// 0 is increase, 1 is decrease.
+            operation: u32,
+            value: Operand,
+        },
+
+        /// Open the menu screen.
+        351 => OpenMenuScreen {},
+
+        /// Open the save screen.
+        352 => OpenSaveScreen {},
+
+        /// Go to the game over screen.
+        353 => GameOver {},
+
+        /// Go to the title screen.
+        354 => ReturnToTitleScreen {},
+
+        /// Run a script.
+        ///
+        /// Extra lines follow as [`CommandData::ScriptExtra`] commands.
+        355 => Script {
+            line: String,
+        },
+
+        /// Run a plugin command.
+        356 => PluginCommand {
+            command: String,
+        },
+
+        /// Run a plugin command.
+        ///
+        /// This is only for MZ games.
+        357 => PluginCommandMz {
+            plugin_name: String,
+            command_name: String,
+            /// The text shown in the editor.
+            comment: String,
+            /// The arguments, in the order they were written.
+            args: Vec<(String, String)>,
+        },
+
+        /// A line of text for [`CommandData::ShowText`].
+        401 => TextData {
+            line: String,
+        },
+
+        /// The start of a choice for [`CommandData::ShowChoices`].
+        402 => When {
+            choice_index: u32,
+            choice_name: String,
+        },
+
+        /// The start of the cancel choice for [`CommandData::ShowChoices`].
+        403 => WhenCancel {
+            choice_index: u32,
+            choice_name: Option<String>,
+        },
+
+        /// The end of [`CommandData::ShowChoices`].
+        404 => WhenEnd {},
+
+        /// A line of text for [`CommandData::ShowScrollingText`].
+        405 => ShowScrollingTextExtra {
+            line: String,
+        },
+
+        /// A line of text for [`CommandData::Comment`].
+        408 => CommentExtra {
+            line: String,
+        },
+
+        /// The else branch of a [`CommandData::ConditionalBranch`].
+        411 => Else {},
+
+        /// The end of a [`CommandData::ConditionalBranch`].
+        412 => ConditionalBranchEnd {},
+
+        /// The end of a [`CommandData::Loop`].
+        413 => RepeatAbove {},
+
+        /// A move command of a [`CommandData::SetMovementRoute`].
+        505 => SetMovementRouteExtra {
+            command: MoveCommand,
+        },
+
+        /// The win branch of a [`CommandData::BattleProcessing`].
+        601 => IfWin {},
+
+        /// The escape branch of a [`CommandData::BattleProcessing`].
+        602 => IfEscape {},
+
+        /// The lose branch of a [`CommandData::BattleProcessing`].
+        603 => IfLose {},
+
+        /// The end of a [`CommandData::BattleProcessing`].
+        604 => BattleResultEnd {},
+
+        /// An extra good for a [`CommandData::ShopProcessing`].
+        605 => ShopProcessingExtra {
+            /// 0 is item, 1 is weapon, 2 is armor.
+            kind: u32,
+            id: u32,
+            /// 0 is the standard price, 1 is a custom price.
+            price_type: u32,
+            price: u32,
+        },
+
+        /// A line of code for [`CommandData::Script`].
+        655 => ScriptExtra {
+            line: String,
+        },
+
+        /// An extra line of the text of a [`CommandData::PluginCommandMz`].
+        ///
+        /// This is only for MZ games.
+        657 => PluginCommandMzExtra {
+            line: String,
+        },
+    }
+}
+
+/// An event command with typed parameters.
+///
+/// This converts to and from an [`EventCommand`] without losing any data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypedCommand {
+    /// The event indent
+    pub indent: u16,
+
+    /// This is only for MZ games.
+    pub collapsed: Option<bool>,
+
+    /// The command data
+    pub data: CommandData,
+
+    /// Fields that are not known to this library
+    pub extra: ExtraFields,
+}
+
+impl TypedCommand {
+    /// Get the command code.
+    pub fn code(&self) -> u32 {
+        self.data.code()
+    }
+}
+
+impl TryFrom<&EventCommand> for TypedCommand {
+    type Error = Error;
+
+    fn try_from(command: &EventCommand) -> Result<Self, Self::Error> {
+        let data = CommandData::from_parameters(command.code, &command.parameters)?;
+
+        Ok(Self {
+            indent: command.indent,
+            collapsed: command.collapsed,
+            data,
+            extra: command.extra.clone(),
+        })
+    }
+}
+
+impl From<TypedCommand> for EventCommand {
+    fn from(command: TypedCommand) -> Self {
+        Self {
+            code: command.code(),
+            indent: command.indent,
+            parameters: command.data.into_parameters(),
+            collapsed: command.collapsed,
+            extra: command.extra,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::CommonEvent;
+    use crate::Map;
+
+    const COMMON_EVENTS_1: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test-data/common-events/CommonEvents1.json"
+    ));
+
+    const MAPS: &[&str] = &[
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/test-data/maps/Map001.json"
+        )),
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/test-data/maps/Map002.json"
+        )),
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/test-data/maps/Map004.json"
+        )),
+    ];
+
+    fn round_trip(command: &EventCommand) {
+        let typed = TypedCommand::try_from(command)
+            .unwrap_or_else(|error| panic!("failed to convert {command:?}: {error}"));
+        let command_ser = EventCommand::from(typed);
+
+        // Compare the json, since map equality ignores key order.
+        let expected = serde_json::to_string(command).expect("failed to serialize");
+        let actual = serde_json::to_string(&command_ser).expect("failed to serialize");
+        assert!(actual == expected, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn round_trip_test_data() {
+        let common_events: Vec<Option<CommonEvent>> =
+            serde_json::from_str(COMMON_EVENTS_1).expect("failed to parse");
+        for common_event in common_events.iter().flatten() {
+            for command in common_event.list.iter() {
+                round_trip(command);
+            }
+        }
+
+        for map in MAPS {
+            let map: Map = serde_json::from_str(map).expect("failed to parse");
+            for event in map.events.iter().flatten() {
+                for page in event.pages.iter() {
+                    for command in page.list.iter() {
+                        round_trip(command);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn plugin_command_mz_args_order() {
+        let command: EventCommand = serde_json::from_str(
+            r#"{"code":357,"indent":0,"parameters":["Plugin","command","text",{"zeta":"1","alpha":"2"}]}"#,
+        )
+        .expect("failed to parse");
+        round_trip(&command);
+
+        let typed = TypedCommand::try_from(&command).expect("failed to convert");
+        let CommandData::PluginCommandMz { args, .. } = typed.data else {
+            panic!("not a plugin command");
+        };
+        assert!(args[0].0 == "zeta");
+        assert!(args[1].0 == "alpha");
+    }
+
+    #[test]
+    fn invalid() {
+        let command = EventCommand {
+            code: 121,
+            indent: 0,
+            parameters: vec![1.into(), 1.into()],
+            collapsed: None,
+            extra: ExtraFields::new(),
+        };
+        assert!(matches!(
+            TypedCommand::try_from(&command),
+            Err(Error::MissingParameter {
+                code: 121,
+                index: 2
+            })
+        ));
+
+        let command = EventCommand {
+            code: 230,
+            indent: 0,
+            parameters: vec![1.5.into()],
+            collapsed: None,
+            extra: ExtraFields::new(),
+        };
+        assert!(matches!(
+            TypedCommand::try_from(&command),
+            Err(Error::InvalidParameter {
+                code: 230,
+                index: 0,
+                ..
+            })
+        ));
+
+        let command = EventCommand {
+            code: 1,
+            indent: 0,
+            parameters: Vec::new(),
+            collapsed: None,
+            extra: ExtraFields::new(),
+        };
+        assert!(matches!(
+            TypedCommand::try_from(&command),
+            Err(Error::UnknownCode { code: 1 })
+        ));
+    }
+}
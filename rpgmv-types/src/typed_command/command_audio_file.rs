@@ -0,0 +1,57 @@
+use crate::AudioFile;
+use crate::Error;
+use crate::param::Param;
+use crate::param::ParamReader;
+use serde_json::Value;
+
+/// An audio file parameter of a command.
+///
+/// The editor does not always write the fields of these in the same order,
+/// so the order is kept to write them back the same way.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandAudioFile {
+    /// The audio file
+    pub audio: AudioFile,
+
+    /// The field names, in the order they were written.
+    ///
+    /// Fields that are not listed here are written after these, in the default order.
+    pub field_order: Vec<String>,
+}
+
+impl From<AudioFile> for CommandAudioFile {
+    fn from(audio: AudioFile) -> Self {
+        Self {
+            audio,
+            field_order: Vec::new(),
+        }
+    }
+}
+
+impl Param for CommandAudioFile {
+    fn read(reader: &mut ParamReader<'_>) -> Result<Self, Error> {
+        reader.read_with("audio file", |value| {
+            let field_order = value.as_object()?.keys().cloned().collect();
+            let audio = serde_json::from_value(value.clone()).ok()?;
+
+            Some(Self { audio, field_order })
+        })
+    }
+
+    fn write(self, parameters: &mut Vec<Value>) {
+        let value = serde_json::to_value(self.audio).expect("failed to serialize audio file");
+        let Value::Object(mut fields) = value else {
+            unreachable!("audio files serialize to objects");
+        };
+
+        let mut ordered = serde_json::Map::new();
+        for key in self.field_order {
+            if let Some(value) = fields.remove(&key) {
+                ordered.insert(key, value);
+            }
+        }
+        ordered.extend(fields);
+
+        parameters.push(Value::Object(ordered));
+    }
+}
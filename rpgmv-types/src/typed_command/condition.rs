@@ -0,0 +1,346 @@
+use super::Operand;
+use crate::Error;
+use crate::param::Param;
+use crate::param::ParamReader;
+use serde_json::Value;
+
+/// The condition of a conditional branch.
+///
+/// This is stored as a type, followed by a variable number of parameters.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Condition {
+    /// Check a switch.
+    Switch {
+        switch_id: u32,
+        /// 0 is on, 1 is off.
+        value: u32,
+    },
+
+    /// Compare a variable.
+    Variable {
+        variable_id: u32,
+        operand: Operand,
+        /// One of `==`, `>=`, `<=`, `>`, `<`, `!=`.
+        comparison: u32,
+    },
+
+    /// Check a self switch of the current event.
+    SelfSwitch {
+        key: String,
+        /// 0 is on, 1 is off.
+        value: u32,
+    },
+
+    /// Compare the timer.
+    Timer {
+        seconds: u32,
+        /// 0 is `>=`, 1 is `<=`.
+        comparison: u32,
+    },
+
+    /// Check an actor.
+    Actor { actor_id: u32, check: ActorCheck },
+
+    /// Check an enemy.
+    Enemy { enemy_index: u32, check: EnemyCheck },
+
+    /// Check the direction of a character.
+    Character { character_id: i32, direction: u32 },
+
+    /// Compare the gold of the party.
+    Gold {
+        gold: u32,
+        /// 0 is `>=`, 1 is `<=`, 2 is `<`.
+        comparison: u32,
+    },
+
+    /// Check if the party has an item.
+    Item { item_id: u32 },
+
+    /// Check if the party has a weapon.
+    Weapon {
+        weapon_id: u32,
+        include_equipped: bool,
+    },
+
+    /// Check if the party has an armor.
+    Armor {
+        armor_id: u32,
+        include_equipped: bool,
+    },
+
+    /// Check if a button is pressed.
+    Button {
+        button: String,
+        /// How the button is pressed.
+        ///
+        /// This is only for MZ games.
+        kind: Option<u32>,
+    },
+
+    /// Evaluate a script.
+    Script { script: String },
+
+    /// Check if the player is in a vehicle.
+    Vehicle { vehicle_id: u32 },
+}
+
+impl Param for Condition {
+    fn read(reader: &mut ParamReader<'_>) -> Result<Self, Error> {
+        let kind: u32 = reader.read()?;
+        match kind {
+            0 => Ok(Self::Switch {
+                switch_id: reader.read()?,
+                value: reader.read()?,
+            }),
+            1 => Ok(Self::Variable {
+                variable_id: reader.read()?,
+                operand: reader.read()?,
+                comparison: reader.read()?,
+            }),
+            2 => Ok(Self::SelfSwitch {
+                key: reader.read()?,
+                value: reader.read()?,
+            }),
+            3 => Ok(Self::Timer {
+                seconds: reader.read()?,
+                comparison: reader.read()?,
+            }),
+            4 => Ok(Self::Actor {
+                actor_id: reader.read()?,
+                check: reader.read()?,
+            }),
+            5 => Ok(Self::Enemy {
+                enemy_index: reader.read()?,
+                check: reader.read()?,
+            }),
+            6 => Ok(Self::Character {
+                character_id: reader.read()?,
+                direction: reader.read()?,
+            }),
+            7 => Ok(Self::Gold {
+                gold: reader.read()?,
+                comparison: reader.read()?,
+            }),
+            8 => Ok(Self::Item {
+                item_id: reader.read()?,
+            }),
+            9 => Ok(Self::Weapon {
+                weapon_id: reader.read()?,
+                include_equipped: reader.read()?,
+            }),
+            10 => Ok(Self::Armor {
+                armor_id: reader.read()?,
+                include_equipped: reader.read()?,
+            }),
+            11 => Ok(Self::Button {
+                button: reader.read()?,
+                kind: reader.read_optional()?,
+            }),
+            12 => Ok(Self::Script {
+                script: reader.read()?,
+            }),
+            13 => Ok(Self::Vehicle {
+                vehicle_id: reader.read()?,
+            }),
+            _ => Err(reader.invalid("condition type")),
+        }
+    }
+
+    fn write(self, parameters: &mut Vec<Value>) {
+        match self {
+            Self::Switch { switch_id, value } => {
+                0_u32.write(parameters);
+                switch_id.write(parameters);
+                value.write(parameters);
+            }
+            Self::Variable {
+                variable_id,
+                operand,
+                comparison,
+            } => {
+                1_u32.write(parameters);
+                variable_id.write(parameters);
+                operand.write(parameters);
+                comparison.write(parameters);
+            }
+            Self::SelfSwitch { key, value } => {
+                2_u32.write(parameters);
+                key.write(parameters);
+                value.write(parameters);
+            }
+            Self::Timer {
+                seconds,
+                comparison,
+            } => {
+                3_u32.write(parameters);
+                seconds.write(parameters);
+                comparison.write(parameters);
+            }
+            Self::Actor { actor_id, check } => {
+                4_u32.write(parameters);
+                actor_id.write(parameters);
+                check.write(parameters);
+            }
+            Self::Enemy { enemy_index, check } => {
+                5_u32.write(parameters);
+                enemy_index.write(parameters);
+                check.write(parameters);
+            }
+            Self::Character {
+                character_id,
+                direction,
+            } => {
+                6_u32.write(parameters);
+                character_id.write(parameters);
+                direction.write(parameters);
+            }
+            Self::Gold { gold, comparison } => {
+                7_u32.write(parameters);
+                gold.write(parameters);
+                comparison.write(parameters);
+            }
+            Self::Item { item_id } => {
+                8_u32.write(parameters);
+                item_id.write(parameters);
+            }
+            Self::Weapon {
+                weapon_id,
+                include_equipped,
+            } => {
+                9_u32.write(parameters);
+                weapon_id.write(parameters);
+                include_equipped.write(parameters);
+            }
+            Self::Armor {
+                armor_id,
+                include_equipped,
+            } => {
+                10_u32.write(parameters);
+                armor_id.write(parameters);
+                include_equipped.write(parameters);
+            }
+            Self::Button { button, kind } => {
+                11_u32.write(parameters);
+                button.write(parameters);
+                if let Some(kind) = kind {
+                    kind.write(parameters);
+                }
+            }
+            Self::Script { script } => {
+                12_u32.write(parameters);
+                script.write(parameters);
+            }
+            Self::Vehicle { vehicle_id } => {
+                13_u32.write(parameters);
+                vehicle_id.write(parameters);
+            }
+        }
+    }
+}
+
+/// What to check about an actor in a conditional branch.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ActorCheck {
+    /// The actor is in the party.
+    InParty,
+
+    /// The actor has a name.
+    Name(String),
+
+    /// The actor has a class.
+    Class(u32),
+
+    /// The actor has learned a skill.
+    Skill(u32),
+
+    /// The actor has a weapon equipped.
+    Weapon(u32),
+
+    /// The actor has an armor equipped.
+    Armor(u32),
+
+    /// The actor is affected by a state.
+    State(u32),
+}
+
+impl Param for ActorCheck {
+    fn read(reader: &mut ParamReader<'_>) -> Result<Self, Error> {
+        let kind: u32 = reader.read()?;
+        match kind {
+            0 => Ok(Self::InParty),
+            1 => Ok(Self::Name(reader.read()?)),
+            2 => Ok(Self::Class(reader.read()?)),
+            3 => Ok(Self::Skill(reader.read()?)),
+            4 => Ok(Self::Weapon(reader.read()?)),
+            5 => Ok(Self::Armor(reader.read()?)),
+            6 => Ok(Self::State(reader.read()?)),
+            _ => Err(reader.invalid("actor check type")),
+        }
+    }
+
+    fn write(self, parameters: &mut Vec<Value>) {
+        match self {
+            Self::InParty => {
+                0_u32.write(parameters);
+            }
+            Self::Name(name) => {
+                1_u32.write(parameters);
+                name.write(parameters);
+            }
+            Self::Class(class_id) => {
+                2_u32.write(parameters);
+                class_id.write(parameters);
+            }
+            Self::Skill(skill_id) => {
+                3_u32.write(parameters);
+                skill_id.write(parameters);
+            }
+            Self::Weapon(weapon_id) => {
+                4_u32.write(parameters);
+                weapon_id.write(parameters);
+            }
+            Self::Armor(armor_id) => {
+                5_u32.write(parameters);
+                armor_id.write(parameters);
+            }
+            Self::State(state_id) => {
+                6_u32.write(parameters);
+                state_id.write(parameters);
+            }
+        }
+    }
+}
+
+/// What to check about an enemy in a conditional branch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EnemyCheck {
+    /// The enemy has appeared.
+    Appeared,
+
+    /// The enemy is affected by a state.
+    State(u32),
+}
+
+impl Param for EnemyCheck {
+    fn read(reader: &mut ParamReader<'_>) -> Result<Self, Error> {
+        let kind: u32 = reader.read()?;
+        match kind {
+            0 => Ok(Self::Appeared),
+            1 => Ok(Self::State(reader.read()?)),
+            _ => Err(reader.invalid("enemy check type")),
+        }
+    }
+
+    fn write(self, parameters: &mut Vec<Value>) {
+        match self {
+            Self::Appeared => {
+                0_u32.write(parameters);
+            }
+            Self::State(state_id) => {
+                1_u32.write(parameters);
+                state_id.write(parameters);
+            }
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::Error;
+use crate::param::Param;
+use crate::param::ParamReader;
+use serde_json::Value;
+
+/// A value that is either a constant, or stored in a variable.
+///
+/// This is stored as 2 parameters, a type and then the value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operand {
+    /// A constant value
+    Constant(i32),
+
+    /// The id of the variable that holds the value
+    Variable(u32),
+}
+
+impl Param for Operand {
+    fn read(reader: &mut ParamReader<'_>) -> Result<Self, Error> {
+        let kind: u32 = reader.read()?;
+        match kind {
+            0 => Ok(Self::Constant(reader.read()?)),
+            1 => Ok(Self::Variable(reader.read()?)),
+            _ => Err(reader.invalid("operand type")),
+        }
+    }
+
+    fn write(self, parameters: &mut Vec<Value>) {
+        match self {
+            Self::Constant(value) => {
+                0_u32.write(parameters);
+                value.write(parameters);
+            }
+            Self::Variable(variable_id) => {
+                1_u32.write(parameters);
+                variable_id.write(parameters);
+            }
+        }
+    }
+}
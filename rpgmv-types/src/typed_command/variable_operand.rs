@@ -0,0 +1,78 @@
+use crate::Error;
+use crate::param::Param;
+use crate::param::ParamReader;
+use serde_json::Value;
+
+/// The operand of a control variables command.
+///
+/// This is stored as a type, followed by a variable number of parameters.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VariableOperand {
+    /// A constant value
+    Constant(i32),
+
+    /// The value of another variable
+    Variable(u32),
+
+    /// A random value in an inclusive range
+    Random { min: i32, max: i32 },
+
+    /// A value from the game state, like the hp of an actor.
+    GameData { kind: u32, param1: i32, param2: i32 },
+
+    /// The result of a script
+    Script(String),
+}
+
+impl Param for VariableOperand {
+    fn read(reader: &mut ParamReader<'_>) -> Result<Self, Error> {
+        let kind: u32 = reader.read()?;
+        match kind {
+            0 => Ok(Self::Constant(reader.read()?)),
+            1 => Ok(Self::Variable(reader.read()?)),
+            2 => Ok(Self::Random {
+                min: reader.read()?,
+                max: reader.read()?,
+            }),
+            3 => Ok(Self::GameData {
+                kind: reader.read()?,
+                param1: reader.read()?,
+                param2: reader.read()?,
+            }),
+            4 => Ok(Self::Script(reader.read()?)),
+            _ => Err(reader.invalid("variable operand type")),
+        }
+    }
+
+    fn write(self, parameters: &mut Vec<Value>) {
+        match self {
+            Self::Constant(value) => {
+                0_u32.write(parameters);
+                value.write(parameters);
+            }
+            Self::Variable(variable_id) => {
+                1_u32.write(parameters);
+                variable_id.write(parameters);
+            }
+            Self::Random { min, max } => {
+                2_u32.write(parameters);
+                min.write(parameters);
+                max.write(parameters);
+            }
+            Self::GameData {
+                kind,
+                param1,
+                param2,
+            } => {
+                3_u32.write(parameters);
+                kind.write(parameters);
+                param1.write(parameters);
+                param2.write(parameters);
+            }
+            Self::Script(script) => {
+                4_u32.write(parameters);
+                script.write(parameters);
+            }
+        }
+    }
+}
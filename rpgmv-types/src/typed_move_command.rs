@@ -0,0 +1,326 @@
+use crate::CommandAudioFile;
+use crate::Error;
+use crate::ExtraFields;
+use crate::MoveCommand;
+use crate::param::typed_data;
+
+typed_data! {
+    /// The typed parameters of a move command.
+    pub enum MoveCommandData {
+        /// The end of a move route.
+        0 => RouteEnd {},
+
+        /// Move down.
+        1 => MoveDown {},
+
+        /// Move left.
+        2 => MoveLeft {},
+
+        /// Move right.
+        3 => MoveRight {},
+
+        /// Move up.
+        4 => MoveUp {},
+
+        /// Move down and to the left.
+        5 => MoveLowerLeft {},
+
+        /// Move down and to the right.
+        6 => MoveLowerRight {},
+
+        /// Move up and to the left.
+        7 => MoveUpperLeft {},
+
+        /// Move up and to the right.
+        8 => MoveUpperRight {},
+
+        /// Move in a random direction.
+        9 => MoveRandom {},
+
+        /// Move toward the player.
+        10 => MoveToward {},
+
+        /// Move away from the player.
+        11 => MoveAway {},
+
+        /// Move 1 step forward.
+        12 => MoveForward {},
+
+        /// Move 1 step backward.
+        13 => MoveBackward {},
+
+        /// Jump by an offset.
+        14 => Jump {
+            x: i32,
+            y: i32,
+        },
+
+        /// Wait for some frames.
+        15 => Wait {
+            duration: u32,
+        },
+
+        /// Turn down.
+        16 => TurnDown {},
+
+        /// Turn left.
+        17 => TurnLeft {},
+
+        /// Turn right.
+        18 => TurnRight {},
+
+        /// Turn up.
+        19 => TurnUp {},
+
+        /// Turn 90 degrees to the right.
+        20 => Turn90DegreesRight {},
+
+        /// Turn 90 degrees to the left.
+        21 => Turn90DegreesLeft {},
+
+        /// Turn 180 degrees.
+        22 => Turn180Degrees {},
+
+        /// Turn 90 degrees to the right or left.
+        23 => Turn90DegreesRightOrLeft {},
+
+        /// Turn in a random direction.
+        24 => TurnRandom {},
+
+        /// Turn toward the player.
+        25 => TurnToward {},
+
+        /// Turn away from the player.
+        26 => TurnAway {},
+
+        /// Turn a switch on.
+        27 => SwitchOn {
+            switch_id: u32,
+        },
+
+        /// Turn a switch off.
+        28 => SwitchOff {
+            switch_id: u32,
+        },
+
+        /// Change the move speed.
+        29 => ChangeSpeed {
+            speed: u32,
+        },
+
+        /// Change the move frequency.
+        30 => ChangeFrequency {
+            frequency: u32,
+        },
+
+        /// Turn on the walking animation.
+        31 => WalkAnimeOn {},
+
+        /// Turn off the walking animation.
+        32 => WalkAnimeOff {},
+
+        /// Turn on the stepping animation.
+        33 => StepAnimeOn {},
+
+        /// Turn off the stepping animation.
+        34 => StepAnimeOff {},
+
+        /// Turn on direction fix.
+        35 => DirectionFixOn {},
+
+        /// Turn off direction fix.
+        36 => DirectionFixOff {},
+
+        /// Turn on moving through other characters and tiles.
+        37 => ThroughOn {},
+
+        /// Turn off moving through other characters and tiles.
+        38 => ThroughOff {},
+
+        /// Turn on transparency.
+        39 => TransparentOn {},
+
+        /// Turn off transparency.
+        40 => TransparentOff {},
+
+        /// Change the character image.
+        41 => ChangeImage {
+            character_name: String,
+            character_index: u32,
+        },
+
+        /// Change the opacity.
+        42 => ChangeOpacity {
+            opacity: u32,
+        },
+
+        /// Change the blend mode.
+        43 => ChangeBlendMode {
+            blend_mode: u32,
+        },
+
+        /// Play a se.
+        44 => PlaySe {
+            audio: CommandAudioFile,
+        },
+
+        /// Run a script.
+        45 => Script {
+            script: String,
+        },
+    }
+}
+
+/// A move command with typed parameters.
+///
+/// This converts to and from a [`MoveCommand`] without losing any data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypedMoveCommand {
+    /// The command indent
+    ///
+    /// This is usually `None`.
+    /// It is `Some(None)` if the indent is null,
+    /// which the editor writes for commands in a Set Movement Route command.
+    pub indent: Option<Option<u32>>,
+
+    /// Whether the raw command has parameters, even if they are empty.
+    ///
+    /// The editor leaves them out for some commands without parameters.
+    pub has_parameters: bool,
+
+    /// The command data
+    pub data: MoveCommandData,
+
+    /// Fields that are not known to this library
+    pub extra: ExtraFields,
+}
+
+impl TypedMoveCommand {
+    /// Make a new typed move command.
+    pub fn new(data: MoveCommandData) -> Self {
+        Self {
+            indent: None,
+            has_parameters: false,
+            data,
+            extra: ExtraFields::new(),
+        }
+    }
+
+    /// Get the command code.
+    pub fn code(&self) -> u32 {
+        self.data.code()
+    }
+}
+
+impl TryFrom<&MoveCommand> for TypedMoveCommand {
+    type Error = Error;
+
+    fn try_from(command: &MoveCommand) -> Result<Self, Self::Error> {
+        let parameters = command.parameters.as_deref().unwrap_or(&[]);
+        let data = MoveCommandData::from_parameters(command.code, parameters)?;
+
+        Ok(Self {
+            indent: command.indent,
+            has_parameters: command.parameters.is_some(),
+            data,
+            extra: command.extra.clone(),
+        })
+    }
+}
+
+impl From<TypedMoveCommand> for MoveCommand {
+    fn from(command: TypedMoveCommand) -> Self {
+        let code = command.code();
+        let parameters = command.data.into_parameters();
+        let parameters = if command.has_parameters || !parameters.is_empty() {
+            Some(parameters)
+        } else {
+            None
+        };
+
+        Self {
+            code,
+            parameters,
+            indent: command.indent,
+            extra: command.extra,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::CommandData;
+    use crate::Map;
+    use crate::TypedCommand;
+
+    const MAPS: &[&str] = &[
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/test-data/maps/Map001.json"
+        )),
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/test-data/maps/Map002.json"
+        )),
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/test-data/maps/Map004.json"
+        )),
+    ];
+
+    fn round_trip(command: &MoveCommand) {
+        let typed = TypedMoveCommand::try_from(command)
+            .unwrap_or_else(|error| panic!("failed to convert {command:?}: {error}"));
+        let command_ser = MoveCommand::from(typed);
+
+        // Compare the json, since map equality ignores key order.
+        let expected = serde_json::to_string(command).expect("failed to serialize");
+        let actual = serde_json::to_string(&command_ser).expect("failed to serialize");
+        assert!(actual == expected, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn round_trip_test_data() {
+        let mut num_commands = 0;
+        for map in MAPS {
+            let map: Map = serde_json::from_str(map).expect("failed to parse");
+            for event in map.events.iter().flatten() {
+                for page in event.pages.iter() {
+                    for command in page.move_route.list.iter() {
+                        round_trip(command);
+                        num_commands += 1;
+                    }
+
+                    for command in page.list.iter() {
+                        let typed = TypedCommand::try_from(command).expect("failed to convert");
+                        if let CommandData::SetMovementRoute { route, .. } = typed.data {
+                            for command in route.list.iter() {
+                                round_trip(command);
+                                num_commands += 1;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        assert!(num_commands > 0);
+    }
+
+    #[test]
+    fn jump() {
+        let command = MoveCommand {
+            code: 14,
+            parameters: Some(vec![0.into(), (-1).into()]),
+            indent: None,
+            extra: ExtraFields::new(),
+        };
+        let typed = TypedMoveCommand::try_from(&command).expect("failed to convert");
+        assert!(typed.data == MoveCommandData::Jump { x: 0, y: -1 });
+
+        let command = MoveCommand::from(TypedMoveCommand::new(MoveCommandData::MoveDown {}));
+        assert!(command.code == 1);
+        assert!(command.parameters.is_none());
+    }
+}
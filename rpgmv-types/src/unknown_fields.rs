@@ -0,0 +1,128 @@
+use crate::Error;
+
+/// Fields that were not recognized while deserializing.
+///
+/// These are kept in their original order, and are serialized after the known fields.
+/// Use [`from_str_strict`] to reject them instead.
+pub type ExtraFields = serde_json::Map<String, serde_json::Value>;
+
+/// A type that keeps the fields it did not recognize while deserializing.
+pub trait UnknownFields {
+    /// Add the paths of the unknown fields of this value and its children.
+    ///
+    /// The path of this value is used as a prefix.
+    fn collect_unknown_fields(&self, path: &str, fields: &mut Vec<String>);
+
+    /// Get the paths of the unknown fields of this value and its children.
+    ///
+    /// Paths look like `events[1].pages[0].someField`.
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields = Vec::new();
+        self.collect_unknown_fields("", &mut fields);
+        fields
+    }
+}
+
+impl<T> UnknownFields for Option<T>
+where
+    T: UnknownFields,
+{
+    fn collect_unknown_fields(&self, path: &str, fields: &mut Vec<String>) {
+        if let Some(value) = self {
+            value.collect_unknown_fields(path, fields);
+        }
+    }
+}
+
+impl<T> UnknownFields for Vec<T>
+where
+    T: UnknownFields,
+{
+    fn collect_unknown_fields(&self, path: &str, fields: &mut Vec<String>) {
+        for (index, value) in self.iter().enumerate() {
+            value.collect_unknown_fields(&format!("{path}[{index}]"), fields);
+        }
+    }
+}
+
+/// Join a field name onto a path.
+pub(crate) fn join_path(path: &str, field: &str) -> String {
+    if path.is_empty() {
+        field.to_string()
+    } else {
+        format!("{path}.{field}")
+    }
+}
+
+/// Parse a value from json, failing if there are any unknown fields.
+///
+/// Normal deserialization keeps unknown fields in an `extra` map.
+/// The error of this lists every unknown field, not just the first one.
+pub fn from_str_strict<T>(input: &str) -> Result<T, Error>
+where
+    T: serde::de::DeserializeOwned + UnknownFields,
+{
+    let value: T = serde_json::from_str(input)?;
+
+    let fields = value.unknown_fields();
+    if !fields.is_empty() {
+        return Err(Error::UnknownFields { fields });
+    }
+
+    Ok(value)
+}
+
+/// Implement [`UnknownFields`] for a struct with an `extra` field.
+///
+/// Fields that may have unknown fields of their own are listed with their json names.
+macro_rules! impl_unknown_fields {
+    ($name:ident { $($json_name:literal => $field:ident),* $(,)? }) => {
+        impl $crate::UnknownFields for $name {
+            fn collect_unknown_fields(&self, path: &str, fields: &mut Vec<String>) {
+                for key in self.extra.keys() {
+                    fields.push($crate::unknown_fields::join_path(path, key));
+                }
+
+                $(
+                    $crate::UnknownFields::collect_unknown_fields(
+                        &self.$field,
+                        &$crate::unknown_fields::join_path(path, $json_name),
+                        fields,
+                    );
+                )*
+            }
+        }
+    };
+}
+
+pub(crate) use impl_unknown_fields;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::CommonEvent;
+
+    #[test]
+    fn tolerant_and_strict() {
+        let input = r#"[null,{"id":1,"list":[{"code":0,"indent":0,"parameters":[],"zeta":1,"alpha":2}],"name":"","switchId":1,"trigger":0,"plugin":true}]"#;
+
+        let common_events: Vec<Option<CommonEvent>> =
+            serde_json::from_str(input).expect("failed to parse");
+        let fields = common_events.unknown_fields();
+        assert!(
+            fields == ["[1].plugin", "[1].list[0].zeta", "[1].list[0].alpha"],
+            "{fields:?}"
+        );
+
+        let output = serde_json::to_string(&common_events).expect("failed to serialize");
+        assert!(output.contains(r#""zeta":1,"alpha":2"#), "{output}");
+        assert!(output.ends_with(r#""plugin":true}]"#), "{output}");
+
+        let error = from_str_strict::<Vec<Option<CommonEvent>>>(input)
+            .expect_err("strict parse should fail");
+        assert!(
+            matches!(error, Error::UnknownFields { ref fields } if fields.len() == 3),
+            "{error}"
+        );
+    }
+}
@@ -1,5 +1,7 @@
+use crate::ExtraFields;
+use crate::unknown_fields::impl_unknown_fields;
+
 #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct WeaponTrait {
     /// ?
     pub code: u32,
@@ -10,10 +12,15 @@ pub struct WeaponTrait {
 
     /// ?
     pub value: f32,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
+impl_unknown_fields!(WeaponTrait {});
+
 #[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq)]
-#[serde(deny_unknown_fields)]
 pub struct Weapon {
     /// The weapon id
     pub id: u32,
@@ -51,4 +58,10 @@ pub struct Weapon {
     /// ?
     #[serde(rename = "wtypeId")]
     pub w_type_id: u32,
+
+    /// Fields that are not known to this library
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
+
+impl_unknown_fields!(Weapon { "traits" => traits });
@@ -0,0 +1,214 @@
+use crate::Error;
+use crate::FileKind;
+use crate::HeaderSpec;
+use crate::KEY_LEN;
+use crate::Key;
+use crate::KnownPlaintext;
+use crate::PartialKey;
+use crate::PngPlaintext;
+use crate::reader::ReaderCore;
+use crate::reader::ReaderPhase;
+use crate::reader::verify_key;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::task::ready;
+use tokio::io::AsyncBufRead;
+use tokio::io::AsyncRead;
+use tokio::io::ReadBuf;
+
+/// An async reader for an encrypted file.
+///
+/// This is the async version of [`crate::Reader`].
+pub struct AsyncReader<R> {
+    reader: R,
+    core: ReaderCore,
+}
+
+impl<R> AsyncReader<R> {
+    /// Create a new reader.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            core: ReaderCore::new(None),
+        }
+    }
+
+    /// Create a new reader with a known key.
+    ///
+    /// This skips key extraction,
+    /// which is needed for files that are not pngs, like audio.
+    pub fn with_key(reader: R, key: Key) -> Self {
+        Self {
+            reader,
+            core: ReaderCore::new(Some(key.into_bytes())),
+        }
+    }
+
+    /// Set the header spec.
+    ///
+    /// This must be called before the header is read, or it will have no effect.
+    pub fn set_header_spec(&mut self, header_spec: HeaderSpec) {
+        self.core.set_header_spec(header_spec);
+    }
+
+    /// Set whether the header should be validated.
+    ///
+    /// See [`crate::Reader::set_lenient`].
+    pub fn set_lenient(&mut self, lenient: bool) {
+        self.core.set_lenient(lenient);
+    }
+
+    /// Get the header that was read, if it has been read.
+    pub fn header(&self) -> Option<&[u8]> {
+        self.core.header()
+    }
+}
+
+impl<R> AsyncReader<R>
+where
+    R: AsyncBufRead + Unpin,
+{
+    fn poll_read_header(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        match self.core.phase() {
+            ReaderPhase::Header => {}
+            _ => return Poll::Ready(Ok(())),
+        }
+
+        let buffer = ready!(Pin::new(&mut self.reader).poll_fill_buf(cx))?;
+        let header_len = self.core.read_header(buffer)?;
+        Pin::new(&mut self.reader).consume(header_len);
+
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_recover_key<P>(
+        &mut self,
+        cx: &mut Context<'_>,
+        plaintext: &P,
+    ) -> Poll<Result<PartialKey, Error>>
+    where
+        P: KnownPlaintext,
+    {
+        loop {
+            match self.core.phase() {
+                ReaderPhase::Header => {
+                    ready!(self.poll_read_header(cx))?;
+                }
+                ReaderPhase::NoKey => break,
+                ReaderPhase::BodyInitial { key, .. } | ReaderPhase::Body { key } => {
+                    return Poll::Ready(Ok(PartialKey::from_key(key)));
+                }
+            }
+        }
+
+        let buffer = ready!(Pin::new(&mut self.reader).poll_fill_buf(cx))?;
+        Poll::Ready(Ok(self.core.recover_key(buffer, plaintext)))
+    }
+
+    /// Read and validate the header.
+    pub async fn read_header(&mut self) -> Result<(), Error> {
+        std::future::poll_fn(|cx| self.poll_read_header(cx)).await
+    }
+
+    /// Set the key manually.
+    ///
+    /// See [`crate::Reader::set_key`].
+    pub async fn set_key(&mut self, key: [u8; KEY_LEN]) -> Result<(), Error> {
+        self.read_header().await?;
+
+        if let ReaderPhase::NoKey = self.core.phase() {
+            self.core.set_key(key);
+        }
+
+        Ok(())
+    }
+
+    /// Determine the encryption key.
+    ///
+    /// See [`crate::Reader::extract_key`].
+    pub async fn extract_key(&mut self) -> Result<[u8; KEY_LEN], Error> {
+        self.recover_key(PngPlaintext)
+            .await?
+            .to_key()
+            .ok_or(Error::BufferTooSmall)
+    }
+
+    /// Recover the encryption key using the known plaintext of a file format.
+    ///
+    /// See [`crate::Reader::recover_key`].
+    pub async fn recover_key<P>(&mut self, plaintext: P) -> Result<PartialKey, Error>
+    where
+        P: KnownPlaintext,
+    {
+        std::future::poll_fn(|cx| self.poll_recover_key(cx, &plaintext)).await
+    }
+
+    /// Check that the key decrypts the start of the body into the given file kind.
+    ///
+    /// See [`crate::Reader::verify_key`].
+    pub async fn verify_key(&mut self, kind: FileKind) -> Result<(), Error> {
+        if !self
+            .recover_key(kind.known_plaintext())
+            .await?
+            .is_complete()
+        {
+            return Err(Error::MissingKey);
+        }
+
+        let key = match self.core.phase() {
+            ReaderPhase::BodyInitial { key, offset: 0 } => key,
+            _ => return Ok(()),
+        };
+
+        std::future::poll_fn(|cx| {
+            let buffer = ready!(Pin::new(&mut self.reader).poll_fill_buf(cx))?;
+            Poll::Ready(verify_key(buffer, key, kind))
+        })
+        .await
+    }
+}
+
+impl<R> AsyncRead for AsyncReader<R>
+where
+    R: AsyncBufRead + Unpin,
+{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+        loop {
+            match this.core.phase() {
+                ReaderPhase::Header => {
+                    ready!(this.poll_read_header(cx)).map_err(std::io::Error::other)?;
+                }
+                ReaderPhase::NoKey => {
+                    ready!(this.poll_recover_key(cx, &PngPlaintext))
+                        .map_err(std::io::Error::other)?
+                        .to_key()
+                        .ok_or_else(|| std::io::Error::other(Error::BufferTooSmall))?;
+                }
+                ReaderPhase::BodyInitial { offset, .. } => {
+                    let buffer = ready!(Pin::new(&mut this.reader).poll_fill_buf(cx))?;
+                    let len = std::cmp::min(
+                        std::cmp::min(buffer.len(), KEY_LEN - offset),
+                        buf.remaining(),
+                    );
+
+                    let mut decrypted = [0; KEY_LEN];
+                    decrypted[..len].copy_from_slice(&buffer[..len]);
+                    Pin::new(&mut this.reader).consume(len);
+                    this.core.decrypt(&mut decrypted[..len]);
+                    buf.put_slice(&decrypted[..len]);
+
+                    return Poll::Ready(Ok(()));
+                }
+                ReaderPhase::Body { .. } => {
+                    return Pin::new(&mut this.reader).poll_read(cx, buf);
+                }
+            }
+        }
+    }
+}
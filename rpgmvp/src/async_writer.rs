@@ -0,0 +1,112 @@
+use crate::Error;
+use crate::HeaderSpec;
+use crate::KEY_LEN;
+use crate::writer::WriterCore;
+use crate::writer::WriterState;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::task::ready;
+use tokio::io::AsyncWrite;
+
+/// An async encrypted file writer.
+///
+/// This is the async version of [`crate::Writer`].
+#[derive(Debug)]
+pub struct AsyncWriter<W> {
+    writer: W,
+    core: WriterCore,
+
+    /// The header being written, and how much of it has been written.
+    header: Option<(Vec<u8>, usize)>,
+}
+
+impl<W> AsyncWriter<W> {
+    /// Make a new writer.
+    pub fn new(writer: W, key: [u8; KEY_LEN]) -> Self {
+        Self {
+            writer,
+            core: WriterCore::new(key),
+            header: None,
+        }
+    }
+
+    /// Set the header spec.
+    ///
+    /// This must be called before the header is written, or it will have no effect.
+    pub fn set_header_spec(&mut self, header_spec: HeaderSpec) {
+        self.core.set_header_spec(header_spec);
+    }
+}
+
+impl<W> AsyncWriter<W>
+where
+    W: AsyncWrite + Unpin,
+{
+    fn poll_write_header(&mut self, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        if self.core.state() != WriterState::Header {
+            return Poll::Ready(Ok(()));
+        }
+
+        let (header, offset) = self
+            .header
+            .get_or_insert_with(|| (self.core.header_spec().bytes().collect(), 0));
+        while *offset < header.len() {
+            let n = ready!(Pin::new(&mut self.writer).poll_write(cx, &header[*offset..]))?;
+            if n == 0 {
+                return Poll::Ready(Err(std::io::ErrorKind::WriteZero.into()));
+            }
+            *offset += n;
+        }
+
+        self.header = None;
+        self.core.finish_header();
+
+        Poll::Ready(Ok(()))
+    }
+
+    /// Write the header.
+    pub async fn write_header(&mut self) -> Result<(), Error> {
+        std::future::poll_fn(|cx| self.poll_write_header(cx)).await?;
+        Ok(())
+    }
+}
+
+impl<W> AsyncWrite for AsyncWriter<W>
+where
+    W: AsyncWrite + Unpin,
+{
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        let this = self.get_mut();
+        loop {
+            match this.core.state() {
+                WriterState::Header => {
+                    ready!(this.poll_write_header(cx))?;
+                }
+                WriterState::BodyInitial { .. } => {
+                    let mut encrypted = [0; KEY_LEN];
+                    let len = this.core.encrypt(buf, &mut encrypted);
+                    let n = ready!(Pin::new(&mut this.writer).poll_write(cx, &encrypted[..len]))?;
+                    this.core.advance(n);
+
+                    return Poll::Ready(Ok(n));
+                }
+                WriterState::Body => {
+                    return Pin::new(&mut this.writer).poll_write(cx, buf);
+                }
+            }
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.get_mut().writer).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.get_mut().writer).poll_shutdown(cx)
+    }
+}
@@ -0,0 +1,111 @@
+use crate::Error;
+use crate::KnownPlaintext;
+use crate::M4aPlaintext;
+use crate::OggPlaintext;
+use crate::PNG_HEADER;
+use crate::PngPlaintext;
+
+/// The minimum number of decrypted bytes needed to verify a file kind.
+const MIN_VERIFY_LEN: usize = 8;
+
+/// The kind of file that was encrypted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileKind {
+    /// A png image
+    Png,
+
+    /// An ogg audio file
+    Ogg,
+
+    /// An m4a audio file
+    M4a,
+}
+
+impl FileKind {
+    /// Get the file kind from an encrypted file extension.
+    ///
+    /// This supports both MV and MZ extensions, and is case-insensitive.
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        let extension = extension.to_ascii_lowercase();
+        match extension.as_str() {
+            "rpgmvp" | "png_" => Some(Self::Png),
+            "rpgmvo" | "ogg_" => Some(Self::Ogg),
+            "rpgmvm" | "m4a_" => Some(Self::M4a),
+            _ => None,
+        }
+    }
+
+    /// Get the file kind from an unencrypted file extension.
+    ///
+    /// This is case-insensitive.
+    pub fn from_unencrypted_extension(extension: &str) -> Option<Self> {
+        let extension = extension.to_ascii_lowercase();
+        match extension.as_str() {
+            "png" => Some(Self::Png),
+            "ogg" => Some(Self::Ogg),
+            "m4a" => Some(Self::M4a),
+            _ => None,
+        }
+    }
+
+    /// Get the extension of the encrypted file, as used by RPGMaker MV.
+    pub fn mv_extension(self) -> &'static str {
+        match self {
+            Self::Png => "rpgmvp",
+            Self::Ogg => "rpgmvo",
+            Self::M4a => "rpgmvm",
+        }
+    }
+
+    /// Get the extension of the encrypted file, as used by RPGMaker MZ.
+    pub fn mz_extension(self) -> &'static str {
+        match self {
+            Self::Png => "png_",
+            Self::Ogg => "ogg_",
+            Self::M4a => "m4a_",
+        }
+    }
+
+    /// Get the extension of the unencrypted file.
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Ogg => "ogg",
+            Self::M4a => "m4a",
+        }
+    }
+
+    /// Get the known plaintext of this file kind, used for key recovery.
+    pub fn known_plaintext(self) -> &'static dyn KnownPlaintext {
+        match self {
+            Self::Png => &PngPlaintext,
+            Self::Ogg => &OggPlaintext,
+            Self::M4a => &M4aPlaintext,
+        }
+    }
+
+    /// Check that the start of a decrypted body looks like this file kind.
+    ///
+    /// This needs at least 8 bytes.
+    /// Up to 16 bytes are checked.
+    pub fn verify(self, decrypted: &[u8]) -> Result<(), Error> {
+        if decrypted.len() < MIN_VERIFY_LEN {
+            return Err(Error::BufferTooSmall);
+        }
+
+        let is_match = match self {
+            Self::Png => decrypted
+                .iter()
+                .zip(PNG_HEADER.iter())
+                .all(|(actual, expected)| actual == expected),
+            Self::Ogg => decrypted.starts_with(b"OggS\0"),
+            Self::M4a => decrypted[4..8] == *b"ftyp",
+        };
+
+        if !is_match {
+            return Err(Error::KeyMismatch { kind: self });
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+use crate::Error;
+use crate::HEADER_PADDING;
+use crate::MAGIC;
+use crate::VERSION;
+use std::borrow::Cow;
+
+const HEADER_LEN: usize = MAGIC.len() + VERSION.len() + HEADER_PADDING.len();
+
+/// A description of the header of an encrypted file.
+///
+/// The header is made of the magic, the version, and zero padding up to the header length.
+/// Some games patch `rpg_core.js` to change any of these.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HeaderSpec {
+    magic: Cow<'static, [u8]>,
+    version: Cow<'static, [u8]>,
+    len: usize,
+}
+
+impl HeaderSpec {
+    /// The header used by RPGMaker MV.
+    pub const MV: Self = Self {
+        magic: Cow::Borrowed(&MAGIC),
+        version: Cow::Borrowed(&VERSION),
+        len: HEADER_LEN,
+    };
+
+    /// The header used by RPGMaker MZ.
+    ///
+    /// This is the same as the MV header.
+    pub const MZ: Self = Self::MV;
+
+    /// Make a new header spec.
+    ///
+    /// Returns `None` if the length cannot fit the magic and version.
+    pub fn new<M, V>(magic: M, version: V, len: usize) -> Option<Self>
+    where
+        M: Into<Cow<'static, [u8]>>,
+        V: Into<Cow<'static, [u8]>>,
+    {
+        let magic = magic.into();
+        let version = version.into();
+
+        if magic.len() + version.len() > len {
+            return None;
+        }
+
+        Some(Self {
+            magic,
+            version,
+            len,
+        })
+    }
+
+    /// Get the magic.
+    pub fn magic(&self) -> &[u8] {
+        &self.magic
+    }
+
+    /// Get the version.
+    pub fn version(&self) -> &[u8] {
+        &self.version
+    }
+
+    /// Get the length of the entire header, in bytes.
+    pub fn header_len(&self) -> usize {
+        self.len
+    }
+
+    /// Get the length of the padding after the version, in bytes.
+    pub fn padding_len(&self) -> usize {
+        self.len - self.magic.len() - self.version.len()
+    }
+
+    /// Validate a header.
+    ///
+    /// The header must be at least as long as the header length,
+    /// and any bytes after that are ignored.
+    ///
+    /// Invalid default headers report the invalid part,
+    /// while invalid custom headers report [`Error::InvalidHeader`].
+    pub fn validate(&self, header: &[u8]) -> Result<(), Error> {
+        let header = header.get(..self.len).ok_or(Error::BufferTooSmall)?;
+        if header.iter().copied().eq(self.bytes()) {
+            return Ok(());
+        }
+
+        if *self != Self::MV {
+            return Err(self.invalid_header(header));
+        }
+
+        let (magic, rest) = header.split_at(MAGIC.len());
+        let (version, padding) = rest.split_at(VERSION.len());
+
+        if let Ok(magic) = magic.try_into()
+            && magic != MAGIC
+        {
+            return Err(Error::InvalidMagic { magic });
+        }
+
+        if let Ok(version) = version.try_into()
+            && version != VERSION
+        {
+            return Err(Error::InvalidVersion { version });
+        }
+
+        match padding.try_into() {
+            Ok(padding) => Err(Error::InvalidHeaderPadding { padding }),
+            Err(_) => Err(self.invalid_header(header)),
+        }
+    }
+
+    /// Make the error for an invalid header that does not match this spec.
+    fn invalid_header(&self, header: &[u8]) -> Error {
+        Error::InvalidHeader {
+            header: header.to_vec(),
+            expected: self.clone(),
+        }
+    }
+
+    /// Get the bytes of a header that matches this header spec.
+    pub fn bytes(&self) -> impl Iterator<Item = u8> + '_ {
+        self.magic
+            .iter()
+            .chain(self.version.iter())
+            .copied()
+            .chain(std::iter::repeat_n(0, self.padding_len()))
+    }
+}
+
+impl Default for HeaderSpec {
+    fn default() -> Self {
+        Self::MV
+    }
+}
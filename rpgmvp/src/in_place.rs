@@ -0,0 +1,57 @@
+use crate::Error;
+use crate::HeaderSpec;
+use crate::KEY_LEN;
+use crate::KnownPlaintext;
+use crate::PngPlaintext;
+
+/// Decrypt an encrypted png that is already in memory.
+///
+/// The standard header is validated and removed, and the key is extracted and returned.
+/// This only works for encrypted pngs.
+/// Use [`decrypt_in_place_with_key`] for other file types.
+pub fn decrypt_in_place(buffer: &mut Vec<u8>) -> Result<[u8; KEY_LEN], Error> {
+    let header_spec = HeaderSpec::MV;
+    header_spec.validate(buffer)?;
+    let header_len = header_spec.header_len();
+
+    let key = PngPlaintext
+        .recover_key(&buffer[header_len..])
+        .to_key()
+        .ok_or(Error::BufferTooSmall)?;
+
+    buffer.drain(..header_len);
+    xor_key(buffer, key);
+
+    Ok(key)
+}
+
+/// Decrypt an encrypted file that is already in memory, using a known key.
+///
+/// The standard header is validated and removed.
+pub fn decrypt_in_place_with_key(buffer: &mut Vec<u8>, key: [u8; KEY_LEN]) -> Result<(), Error> {
+    let header_spec = HeaderSpec::MV;
+    header_spec.validate(buffer)?;
+    let header_len = header_spec.header_len();
+
+    buffer.drain(..header_len);
+    xor_key(buffer, key);
+
+    Ok(())
+}
+
+/// Encrypt a file that is already in memory.
+///
+/// The standard header is prepended.
+/// This will only allocate if the buffer does not have room for the header.
+pub fn encrypt_in_place(buffer: &mut Vec<u8>, key: [u8; KEY_LEN]) {
+    xor_key(buffer, key);
+
+    buffer.splice(0..0, HeaderSpec::MV.bytes());
+}
+
+/// Xor the start of a body with a key.
+fn xor_key(body: &mut [u8], key: [u8; KEY_LEN]) {
+    for (body_byte, key_byte) in body.iter_mut().zip(key.iter().copied()) {
+        *body_byte ^= key_byte;
+    }
+}
@@ -0,0 +1,81 @@
+use crate::Error;
+use crate::KEY_LEN;
+
+/// An encryption key.
+///
+/// This can be parsed from the hex `encryptionKey` string in `System.json`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Key([u8; KEY_LEN]);
+
+impl Key {
+    /// Make a new key from raw bytes.
+    pub fn new(bytes: [u8; KEY_LEN]) -> Self {
+        Self(bytes)
+    }
+
+    /// Get the raw bytes of this key.
+    pub fn into_bytes(self) -> [u8; KEY_LEN] {
+        self.0
+    }
+}
+
+impl From<[u8; KEY_LEN]> for Key {
+    fn from(bytes: [u8; KEY_LEN]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<Key> for [u8; KEY_LEN] {
+    fn from(key: Key) -> Self {
+        key.0
+    }
+}
+
+impl AsRef<[u8]> for Key {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl std::str::FromStr for Key {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let invalid_key = || Error::InvalidKey {
+            key: input.to_string(),
+        };
+
+        let input_bytes = input.as_bytes();
+        if input_bytes.len() != KEY_LEN * 2 {
+            return Err(invalid_key());
+        }
+
+        let mut key = [0; KEY_LEN];
+        for (key_byte, chunk) in key.iter_mut().zip(input_bytes.chunks_exact(2)) {
+            let high = decode_hex_digit(chunk[0]).ok_or_else(invalid_key)?;
+            let low = decode_hex_digit(chunk[1]).ok_or_else(invalid_key)?;
+            *key_byte = (high << 4) | low;
+        }
+
+        Ok(Self(key))
+    }
+}
+
+impl std::fmt::Display for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for byte in self.0.iter() {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Decode a single hex digit, accepting both cases.
+fn decode_hex_digit(digit: u8) -> Option<u8> {
+    match digit {
+        b'0'..=b'9' => Some(digit - b'0'),
+        b'a'..=b'f' => Some(digit - b'a' + 10),
+        b'A'..=b'F' => Some(digit - b'A' + 10),
+        _ => None,
+    }
+}
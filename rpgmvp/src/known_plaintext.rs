@@ -0,0 +1,267 @@
+use crate::Error;
+use crate::KEY_LEN;
+use crate::PNG_HEADER;
+
+/// The type of the first box of an m4a file.
+const M4A_FTYP: &[u8] = b"ftyp";
+
+/// The major brand of an m4a file.
+const M4A_MAJOR_BRAND: &[u8] = b"M4A ";
+
+/// Top-level box types that may follow the `ftyp` box.
+const M4A_NEXT_BOX_TYPES: &[&[u8]] = &[
+    b"free", b"skip", b"wide", b"moov", b"mdat", b"pdin", b"uuid",
+];
+
+/// A key where only some of the bytes are known.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PartialKey {
+    key: [u8; KEY_LEN],
+    known: [bool; KEY_LEN],
+}
+
+impl PartialKey {
+    /// Make a new partial key, where no bytes are known.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Make a new partial key, where all bytes are known.
+    pub fn from_key(key: [u8; KEY_LEN]) -> Self {
+        Self {
+            key,
+            known: [true; KEY_LEN],
+        }
+    }
+
+    /// Get the key byte at the given index, if it is known.
+    pub fn get(&self, index: usize) -> Option<u8> {
+        if *self.known.get(index)? {
+            Some(self.key[index])
+        } else {
+            None
+        }
+    }
+
+    /// Mark the key byte at the given index as known.
+    ///
+    /// # Panics
+    /// Panics if the index is out of bounds.
+    pub fn set(&mut self, index: usize, byte: u8) {
+        self.key[index] = byte;
+        self.known[index] = true;
+    }
+
+    /// Check if the key byte at the given index is known.
+    pub fn is_known(&self, index: usize) -> bool {
+        self.known.get(index).copied().unwrap_or(false)
+    }
+
+    /// Get the number of known key bytes.
+    pub fn known_len(&self) -> usize {
+        self.known.iter().filter(|known| **known).count()
+    }
+
+    /// Check if every key byte is known.
+    pub fn is_complete(&self) -> bool {
+        self.known.iter().all(|known| *known)
+    }
+
+    /// Get the full key, if every byte is known.
+    pub fn to_key(&self) -> Option<[u8; KEY_LEN]> {
+        if self.is_complete() {
+            Some(self.key)
+        } else {
+            None
+        }
+    }
+
+    /// Combine the known bytes of another partial key into this one.
+    ///
+    /// This is useful for recovering a key from multiple files of different types.
+    /// This fails if both keys know a byte, but disagree on its value.
+    pub fn merge(&mut self, other: &Self) -> Result<(), Error> {
+        for index in 0..KEY_LEN {
+            match (self.get(index), other.get(index)) {
+                (Some(byte), Some(other_byte)) if byte != other_byte => {
+                    return Err(Error::KeyConflict { index });
+                }
+                (None, Some(other_byte)) => {
+                    self.set(index, other_byte);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Recover key bytes by xoring an encrypted body with its expected plaintext prefix.
+    ///
+    /// Only indices where both an encrypted and an expected byte exist are recovered.
+    fn from_prefix(body: &[u8], expected: &[u8]) -> Self {
+        let mut key = Self::new();
+        for (index, (body_byte, expected_byte)) in body
+            .iter()
+            .copied()
+            .zip(expected.iter().copied())
+            .take(KEY_LEN)
+            .enumerate()
+        {
+            key.set(index, body_byte ^ expected_byte);
+        }
+        key
+    }
+}
+
+/// A file format with a known plaintext prefix, which can be used to recover a key.
+pub trait KnownPlaintext {
+    /// Recover as many key bytes as possible from the start of an encrypted body.
+    ///
+    /// The body is the file after the rpgmv header,
+    /// where only the first 16 bytes are encrypted.
+    /// It may be shorter than the full file,
+    /// though some formats need more than 16 bytes to confirm all key bytes.
+    fn recover_key(&self, body: &[u8]) -> PartialKey;
+}
+
+impl<T> KnownPlaintext for &T
+where
+    T: KnownPlaintext + ?Sized,
+{
+    fn recover_key(&self, body: &[u8]) -> PartialKey {
+        (**self).recover_key(body)
+    }
+}
+
+/// Png files, usually `.rpgmvp`.
+///
+/// The first 16 bytes are always the png signature and the start of the IHDR chunk,
+/// so this recovers the entire key.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PngPlaintext;
+
+impl KnownPlaintext for PngPlaintext {
+    fn recover_key(&self, body: &[u8]) -> PartialKey {
+        PartialKey::from_prefix(body, PNG_HEADER)
+    }
+}
+
+/// Ogg files, usually `.rpgmvo`.
+///
+/// The first 14 bytes of the first page are fixed.
+/// The last 2 key bytes cover part of the random stream serial number.
+/// These are recovered by checking candidates against the page crc,
+/// which requires the entire first page to be present in the body.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OggPlaintext;
+
+impl KnownPlaintext for OggPlaintext {
+    fn recover_key(&self, body: &[u8]) -> PartialKey {
+        let mut key = PartialKey::from_prefix(body, ogg_page::FIRST_PAGE_HEADER);
+
+        let page = match get_ogg_page(body) {
+            Some(page) => page,
+            None => return key,
+        };
+
+        // The first page always has a sequence number of 0.
+        // This is unencrypted, so it can cheaply rule out other formats.
+        if page[ogg_page::PAGE_SEQUENCE_OFFSET..ogg_page::PAGE_SEQUENCE_OFFSET + 4] != [0; 4] {
+            return key;
+        }
+
+        let unknown_start = ogg_page::FIRST_PAGE_HEADER.len();
+        let mut page = page.to_vec();
+        page[..unknown_start].copy_from_slice(ogg_page::FIRST_PAGE_HEADER);
+
+        // Only the first 2 bytes of the serial number are encrypted.
+        // The crc will catch any change of 2 adjacent bytes,
+        // so there can only ever be 1 match.
+        let candidates = (0..=u16::MAX).map(|candidate| {
+            let [low, high] = candidate.to_le_bytes();
+            [low, high, page[unknown_start + 2], page[unknown_start + 3]]
+        });
+        if let Some(serial) = ogg_page::find_serial(&page, candidates) {
+            for (offset, (serial_byte, encrypted_byte)) in
+                serial[..2].iter().zip(&body[unknown_start..]).enumerate()
+            {
+                key.set(unknown_start + offset, serial_byte ^ encrypted_byte);
+            }
+        }
+
+        key
+    }
+}
+
+/// M4a files, usually `.rpgmvm`.
+///
+/// These start with an `ftyp` box with an `M4A ` major brand.
+/// The box size is recovered by looking for the following box,
+/// while the low 2 bytes of the minor version are left unknown.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct M4aPlaintext;
+
+impl KnownPlaintext for M4aPlaintext {
+    fn recover_key(&self, body: &[u8]) -> PartialKey {
+        // The size is a big endian u32.
+        // Boxes this small will always have the first 3 bytes be 0.
+        // The high bytes of the minor version are 0 in practice.
+        let mut expected = [0; 14];
+        expected[4..8].copy_from_slice(M4A_FTYP);
+        expected[8..12].copy_from_slice(M4A_MAJOR_BRAND);
+
+        let mut key = PartialKey::from_prefix(body, &expected);
+        // The last byte of the size is not known yet.
+        key.known[3] = false;
+
+        let encrypted_size_byte = match body.get(3) {
+            Some(byte) => *byte,
+            None => return key,
+        };
+
+        // The ftyp box has the size, type, major brand, minor version,
+        // and a list of 4 byte compatible brands.
+        let mut found = None;
+        for size in (16..=usize::from(u8::MAX)).step_by(4) {
+            let brands = match body.get(KEY_LEN..size) {
+                Some(brands) => brands,
+                None => break,
+            };
+            let next_box_type = match body.get(size + 4..size + 8) {
+                Some(next_box_type) => next_box_type,
+                None => break,
+            };
+
+            let is_valid = brands
+                .iter()
+                .all(|byte| byte.is_ascii_alphanumeric() || *byte == b' ')
+                && M4A_NEXT_BOX_TYPES.contains(&next_box_type);
+            if is_valid {
+                if found.is_some() {
+                    // Ambiguous, so we can't say anything.
+                    found = None;
+                    break;
+                }
+                found = Some(size);
+            }
+        }
+
+        if let Some(size) = found {
+            let size = u8::try_from(size).expect("size does not fit in a u8");
+            key.set(3, encrypted_size_byte ^ size);
+        }
+
+        key
+    }
+}
+
+/// Get the first ogg page from a body, where the first 16 bytes are encrypted.
+fn get_ogg_page(body: &[u8]) -> Option<&[u8]> {
+    let segment_count = usize::from(*body.get(ogg_page::SEGMENT_COUNT_OFFSET)?);
+    let segment_table_start = ogg_page::SEGMENT_TABLE_OFFSET;
+    let segment_table = body.get(segment_table_start..segment_table_start + segment_count)?;
+    let data_len: usize = segment_table.iter().copied().map(usize::from).sum();
+
+    body.get(..segment_table_start + segment_count + data_len)
+}
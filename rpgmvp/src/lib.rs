@@ -1,9 +1,36 @@
+#[cfg(feature = "tokio")]
+mod async_reader;
+#[cfg(feature = "tokio")]
+mod async_writer;
+mod file_kind;
+mod header_spec;
+mod in_place;
+mod key;
+mod known_plaintext;
 mod reader;
 mod writer;
 
+#[cfg(feature = "tokio")]
+pub use self::async_reader::AsyncReader;
+#[cfg(feature = "tokio")]
+pub use self::async_writer::AsyncWriter;
+pub use self::file_kind::FileKind;
+pub use self::header_spec::HeaderSpec;
+pub use self::in_place::decrypt_in_place;
+pub use self::in_place::decrypt_in_place_with_key;
+pub use self::in_place::encrypt_in_place;
+pub use self::key::Key;
+pub use self::known_plaintext::KnownPlaintext;
+pub use self::known_plaintext::M4aPlaintext;
+pub use self::known_plaintext::OggPlaintext;
+pub use self::known_plaintext::PartialKey;
+pub use self::known_plaintext::PngPlaintext;
 pub use self::reader::Reader;
 pub use self::writer::Writer;
 
+/// The length of an encryption key, in bytes.
+pub const KEY_LEN: usize = 16;
+
 const MAGIC: [u8; 9] = *b"RPGMV\0\0\0\0";
 const VERSION: [u8; 3] = [3, 1, 0];
 const HEADER_PADDING: [u8; 4] = [0, 0, 0, 0];
@@ -25,14 +52,70 @@ pub enum Error {
     #[error("invalid header padding \"{padding:?}\", expected \"{HEADER_PADDING:?}\"")]
     InvalidHeaderPadding { padding: [u8; 4] },
 
+    #[error("invalid header \"{header:X?}\", expected a header matching {expected:?}")]
+    InvalidHeader {
+        header: Vec<u8>,
+        expected: HeaderSpec,
+    },
+
     #[error("the provided buffer is too small")]
     BufferTooSmall,
+
+    #[error("invalid key \"{key}\", expected {} hex digits", KEY_LEN * 2)]
+    InvalidKey { key: String },
+
+    #[error("key byte {index} has conflicting values")]
+    KeyConflict { index: usize },
+
+    #[error("the key is missing")]
+    MissingKey,
+
+    #[error("the key does not decrypt the file into a {kind:?} file")]
+    KeyMismatch { kind: FileKind },
+}
+
+/// Seek the inner stream of a reader or writer, given a position in the body.
+///
+/// Returns the new position in the body.
+fn seek_body<S>(
+    stream: &mut S,
+    position: std::io::SeekFrom,
+    header_len: usize,
+) -> std::io::Result<u64>
+where
+    S: std::io::Seek,
+{
+    let header_len = header_len as u64;
+    let target = match position {
+        std::io::SeekFrom::Start(offset) => offset.checked_add(header_len),
+        std::io::SeekFrom::End(offset) => {
+            let current = stream.stream_position()?;
+            let end = stream.seek(std::io::SeekFrom::End(0))?;
+            stream.seek(std::io::SeekFrom::Start(current))?;
+            end.checked_add_signed(offset)
+        }
+        std::io::SeekFrom::Current(offset) => stream.stream_position()?.checked_add_signed(offset),
+    };
+    let target = target
+        .filter(|target| *target >= header_len)
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+    stream.seek(std::io::SeekFrom::Start(target))?;
+
+    Ok(target - header_len)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
     use std::fs::File;
+    use std::io::Read;
+    use std::io::Seek;
+    use std::io::SeekFrom;
     use std::io::Write;
 
     /// File taken from https://github.com/kins-dev/rpgmaker_mv_decoder/blob/main/tests/assets/Actor1.rpgmvp
@@ -60,4 +143,303 @@ mod test {
 
         assert!(ENCRYPTED == encrypted);
     }
+
+    #[test]
+    fn reader_with_key() {
+        let mut reader = Reader::new(ENCRYPTED);
+        let mut expected = Vec::new();
+        std::io::copy(&mut reader, &mut expected).expect("failed to copy");
+        let key = reader.extract_key().expect("failed to extract key");
+
+        let key_hex = Key::new(key).to_string();
+        let parsed_key: Key = key_hex.parse().expect("failed to parse key");
+        assert!(parsed_key.into_bytes() == key);
+        assert!(
+            key_hex
+                .to_uppercase()
+                .parse::<Key>()
+                .expect("failed to parse key")
+                == parsed_key
+        );
+        assert!("00".parse::<Key>().is_err());
+
+        let mut reader = Reader::with_key(ENCRYPTED, parsed_key);
+        let mut decrypted = Vec::new();
+        std::io::copy(&mut reader, &mut decrypted).expect("failed to copy");
+        assert!(decrypted == expected);
+
+        let mut reader = Reader::new(ENCRYPTED);
+        reader.set_key(key).expect("failed to set key");
+        let mut decrypted = Vec::new();
+        std::io::copy(&mut reader, &mut decrypted).expect("failed to copy");
+        assert!(decrypted == expected);
+    }
+
+    /// Encrypt a body with the key used by the test file.
+    fn encrypt_with_test_key(body: &[u8]) -> Vec<u8> {
+        let key = Reader::new(ENCRYPTED)
+            .extract_key()
+            .expect("failed to extract key");
+
+        let mut encrypted = Vec::new();
+        let mut writer = Writer::new(&mut encrypted, key);
+        writer.write_all(body).expect("failed to write body");
+
+        encrypted
+    }
+
+    #[test]
+    fn recover_key_ogg() {
+        // A minimal vorbis identification header page.
+        let mut page = Vec::new();
+        page.extend(b"OggS\0\x02");
+        page.extend(0_u64.to_le_bytes());
+        page.extend(0x1234_5678_u32.to_le_bytes());
+        page.extend(0_u32.to_le_bytes());
+        page.extend(0_u32.to_le_bytes());
+        page.push(1);
+        page.push(30);
+        page.extend(b"\x01vorbis");
+        page.extend([0; 23]);
+        let crc = ogg_page::crc(&page);
+        page[22..26].copy_from_slice(&crc.to_le_bytes());
+
+        let encrypted = encrypt_with_test_key(&page);
+
+        let mut reader = Reader::new(encrypted.as_slice());
+        let key = reader
+            .recover_key(OggPlaintext)
+            .expect("failed to recover key");
+        assert!(key.is_complete());
+
+        let mut decrypted = Vec::new();
+        std::io::copy(&mut reader, &mut decrypted).expect("failed to copy");
+        assert!(decrypted == page);
+
+        // Without the full page, only the fixed bytes are recovered.
+        let mut reader = Reader::new(&encrypted[..40]);
+        let key = reader
+            .recover_key(OggPlaintext)
+            .expect("failed to recover key");
+        assert!(key.known_len() == 14);
+        assert!(!key.is_known(14));
+
+        // Later pages are never the start of a file.
+        page[18..22].copy_from_slice(&1_u32.to_le_bytes());
+        page[22..26].fill(0);
+        let crc = ogg_page::crc(&page);
+        page[22..26].copy_from_slice(&crc.to_le_bytes());
+
+        let encrypted = encrypt_with_test_key(&page);
+        let mut reader = Reader::new(encrypted.as_slice());
+        let key = reader
+            .recover_key(OggPlaintext)
+            .expect("failed to recover key");
+        assert!(!key.is_complete());
+    }
+
+    #[test]
+    fn recover_key_m4a() {
+        let mut file = Vec::new();
+        file.extend(0x1C_u32.to_be_bytes());
+        file.extend(b"ftypM4A ");
+        file.extend(0x200_u32.to_be_bytes());
+        file.extend(b"M4A mp42isom");
+        file.extend(8_u32.to_be_bytes());
+        file.extend(b"free");
+
+        let encrypted = encrypt_with_test_key(&file);
+
+        let mut reader = Reader::new(encrypted.as_slice());
+        let m4a_key = reader
+            .recover_key(M4aPlaintext)
+            .expect("failed to recover key");
+        assert!(m4a_key.known_len() == 14);
+        assert!(!m4a_key.is_known(14));
+        assert!(!m4a_key.is_known(15));
+
+        let mut png_key = Reader::new(ENCRYPTED)
+            .recover_key(PngPlaintext)
+            .expect("failed to recover key");
+        png_key.merge(&m4a_key).expect("failed to merge keys");
+        assert!(png_key.is_complete());
+
+        let mut bad_key = PartialKey::new();
+        bad_key.set(0, !m4a_key.get(0).unwrap());
+        assert!(png_key.merge(&bad_key).is_err());
+    }
+
+    #[test]
+    fn reader_seek() {
+        let mut reader = Reader::new(ENCRYPTED);
+        let mut expected = Vec::new();
+        std::io::copy(&mut reader, &mut expected).expect("failed to copy");
+
+        let mut reader = Reader::new(std::io::Cursor::new(ENCRYPTED));
+        for (position, expected_start) in [
+            (SeekFrom::Start(100), 100),
+            (SeekFrom::Start(3), 3),
+            (SeekFrom::Current(5), 3 + 8 + 5),
+            (SeekFrom::End(-20), expected.len() - 20),
+            (SeekFrom::Start(0), 0),
+        ] {
+            let actual_start = reader.seek(position).expect("failed to seek");
+            assert!(actual_start == u64::try_from(expected_start).unwrap());
+
+            let mut buffer = [0; 8];
+            reader.read_exact(&mut buffer).expect("failed to read");
+            assert!(buffer == expected[expected_start..expected_start + 8]);
+        }
+
+        assert!(reader.seek(SeekFrom::Current(-100)).is_err());
+        assert!(reader.stream_position().expect("failed to get position") == 8);
+    }
+
+    #[test]
+    fn writer_seek() {
+        let mut reader = Reader::new(ENCRYPTED);
+        let mut decrypted = Vec::new();
+        std::io::copy(&mut reader, &mut decrypted).expect("failed to copy");
+        let key = reader.extract_key().expect("failed to extract key");
+
+        // Write the body out of order.
+        let mut encrypted = std::io::Cursor::new(Vec::new());
+        let mut writer = Writer::new(&mut encrypted, key);
+        writer.seek(SeekFrom::Start(10)).expect("failed to seek");
+        writer.write_all(&decrypted[10..]).expect("failed to write");
+        writer.seek(SeekFrom::Start(0)).expect("failed to seek");
+        writer.write_all(&decrypted[..10]).expect("failed to write");
+
+        assert!(ENCRYPTED == encrypted.into_inner());
+    }
+
+    #[test]
+    fn in_place() {
+        let mut reader = Reader::new(ENCRYPTED);
+        let mut expected = Vec::new();
+        std::io::copy(&mut reader, &mut expected).expect("failed to copy");
+        let expected_key = reader.extract_key().expect("failed to extract key");
+
+        let mut buffer = ENCRYPTED.to_vec();
+        let key = decrypt_in_place(&mut buffer).expect("failed to decrypt");
+        assert!(key == expected_key);
+        assert!(buffer == expected);
+
+        encrypt_in_place(&mut buffer, key);
+        assert!(buffer == ENCRYPTED);
+
+        let mut buffer = b"RPGMV\0\0\0\0\x03\x01\x00\x00\x00\x00\x01".to_vec();
+        assert!(matches!(
+            decrypt_in_place_with_key(&mut buffer, key),
+            Err(Error::InvalidHeaderPadding { .. })
+        ));
+    }
+
+    #[test]
+    fn verify_key() {
+        let mut reader = Reader::new(ENCRYPTED);
+        reader
+            .verify_key(FileKind::Png)
+            .expect("failed to verify key");
+
+        let mut reader = Reader::new(ENCRYPTED);
+        assert!(matches!(
+            reader.verify_key(FileKind::Ogg),
+            Err(Error::MissingKey)
+        ));
+
+        let mut reader = Reader::with_key(ENCRYPTED, Key::new([0; KEY_LEN]));
+        assert!(matches!(
+            reader.verify_key(FileKind::Png),
+            Err(Error::KeyMismatch {
+                kind: FileKind::Png
+            })
+        ));
+
+        assert!(FileKind::from_extension("RPGMVO") == Some(FileKind::Ogg));
+        assert!(FileKind::from_extension("m4a_") == Some(FileKind::M4a));
+        assert!(FileKind::from_extension("png").is_none());
+        assert!(FileKind::Ogg.extension() == "ogg");
+        for kind in [FileKind::Png, FileKind::Ogg, FileKind::M4a] {
+            assert!(FileKind::from_extension(kind.mv_extension()) == Some(kind));
+            assert!(FileKind::from_extension(kind.mz_extension()) == Some(kind));
+            assert!(FileKind::from_unencrypted_extension(kind.extension()) == Some(kind));
+        }
+    }
+
+    #[test]
+    fn header_spec() {
+        let mut reader = Reader::new(ENCRYPTED);
+        let mut decrypted = Vec::new();
+        std::io::copy(&mut reader, &mut decrypted).expect("failed to copy");
+        let key = reader.extract_key().expect("failed to extract key");
+        assert!(reader.header() == Some(&ENCRYPTED[..16]));
+
+        let header_spec =
+            HeaderSpec::new(b"CUSTOM".as_slice(), [1, 0].as_slice(), 20).expect("invalid spec");
+        let mut encrypted = Vec::new();
+        let mut writer = Writer::new(&mut encrypted, key);
+        writer.set_header_spec(header_spec.clone());
+        writer.write_all(&decrypted).expect("failed to write");
+        assert!(encrypted.starts_with(b"CUSTOM\x01\0\0\0\0\0\0\0\0\0\0\0\0\0"));
+
+        let mut reader = Reader::new(encrypted.as_slice());
+        assert!(matches!(
+            reader.read_header(),
+            Err(Error::InvalidMagic { .. })
+        ));
+
+        let mut reader = Reader::new(ENCRYPTED);
+        reader.set_header_spec(header_spec.clone());
+        assert!(matches!(
+            reader.read_header(),
+            Err(Error::InvalidHeader { .. })
+        ));
+
+        let mut reader = Reader::new(encrypted.as_slice());
+        reader.set_header_spec(header_spec);
+        let mut actual = Vec::new();
+        std::io::copy(&mut reader, &mut actual).expect("failed to copy");
+        assert!(actual == decrypted);
+
+        let mut reader = Reader::new(encrypted.as_slice());
+        reader.set_header_spec(HeaderSpec::new(Vec::new(), Vec::new(), 20).unwrap());
+        reader.set_lenient(true);
+        reader.read_header().expect("failed to read header");
+        assert!(reader.header() == Some(&encrypted[..20]));
+
+        assert!(HeaderSpec::new(b"RPGMV".as_slice(), Vec::new(), 4).is_none());
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn async_reader_writer() {
+        use tokio::io::AsyncReadExt;
+        use tokio::io::AsyncWriteExt;
+
+        let mut reader = Reader::new(ENCRYPTED);
+        let mut expected = Vec::new();
+        std::io::copy(&mut reader, &mut expected).expect("failed to copy");
+
+        let mut reader = AsyncReader::new(ENCRYPTED);
+        let key = reader.extract_key().await.expect("failed to extract key");
+        let mut decrypted = Vec::new();
+        reader
+            .read_to_end(&mut decrypted)
+            .await
+            .expect("failed to read");
+        assert!(decrypted == expected);
+
+        let mut encrypted = Vec::new();
+        let mut writer = AsyncWriter::new(&mut encrypted, key);
+        writer.write_all(&decrypted).await.expect("failed to write");
+        writer.flush().await.expect("failed to flush");
+        assert!(encrypted == ENCRYPTED);
+
+        let mut reader = AsyncReader::with_key(ENCRYPTED, Key::new([0; KEY_LEN]));
+        assert!(matches!(
+            reader.verify_key(FileKind::Png).await,
+            Err(Error::KeyMismatch { .. })
+        ));
+    }
 }
@@ -1,17 +1,21 @@
 use crate::Error;
-use crate::HEADER_PADDING;
-use crate::MAGIC;
-use crate::PNG_HEADER;
-use crate::VERSION;
+use crate::FileKind;
+use crate::HeaderSpec;
+use crate::KEY_LEN;
+use crate::Key;
+use crate::KnownPlaintext;
+use crate::PartialKey;
+use crate::PngPlaintext;
+use crate::seek_body;
 use std::io::BufRead;
 use std::io::Read;
-
-const KEY_LEN: usize = 16;
+use std::io::Seek;
+use std::io::SeekFrom;
 
 /// The reader state
 enum ReaderState {
-    /// Reads header next
-    Header,
+    /// Reads header next, with a key that may have been provided by the user.
+    Header { key: Option<[u8; KEY_LEN]> },
     /// Reads the start of the body next, but no key has been determined
     BodyInitialNoKey,
     /// Reads the start of the body next, but a key has been determined
@@ -20,10 +24,155 @@ enum ReaderState {
     Body { key: [u8; 16] },
 }
 
-/// A reader for an encrypted PNG file
+/// What a reader needs to do next.
+pub(crate) enum ReaderPhase {
+    /// The header must be read.
+    Header,
+    /// The key must be determined.
+    NoKey,
+    /// The encrypted start of the body must be read.
+    BodyInitial { key: [u8; KEY_LEN], offset: usize },
+    /// The unencrypted rest of the body must be read.
+    Body { key: [u8; KEY_LEN] },
+}
+
+/// The io-independent part of a reader.
+///
+/// This is shared between the sync and async readers.
+/// Callers peek at the inner reader's buffer and consume what this tells them to.
+pub(crate) struct ReaderCore {
+    state: ReaderState,
+    header_spec: HeaderSpec,
+    lenient: bool,
+    header: Option<Vec<u8>>,
+}
+
+impl ReaderCore {
+    /// Make a new reader core.
+    pub(crate) fn new(key: Option<[u8; KEY_LEN]>) -> Self {
+        Self {
+            state: ReaderState::Header { key },
+            header_spec: HeaderSpec::MV,
+            lenient: false,
+            header: None,
+        }
+    }
+
+    pub(crate) fn set_header_spec(&mut self, header_spec: HeaderSpec) {
+        self.header_spec = header_spec;
+    }
+
+    pub(crate) fn set_lenient(&mut self, lenient: bool) {
+        self.lenient = lenient;
+    }
+
+    pub(crate) fn header(&self) -> Option<&[u8]> {
+        self.header.as_deref()
+    }
+
+    pub(crate) fn header_len(&self) -> usize {
+        self.header_spec.header_len()
+    }
+
+    /// Get what needs to be done next.
+    pub(crate) fn phase(&self) -> ReaderPhase {
+        match self.state {
+            ReaderState::Header { .. } => ReaderPhase::Header,
+            ReaderState::BodyInitialNoKey => ReaderPhase::NoKey,
+            ReaderState::BodyInitial { key, offset } => ReaderPhase::BodyInitial { key, offset },
+            ReaderState::Body { key } => ReaderPhase::Body { key },
+        }
+    }
+
+    /// Validate the header at the start of the buffer.
+    ///
+    /// Returns the number of bytes to consume.
+    pub(crate) fn read_header(&mut self, buffer: &[u8]) -> Result<usize, Error> {
+        let key = match self.state {
+            ReaderState::Header { key } => key,
+            _ => return Ok(0),
+        };
+
+        let header = buffer
+            .get(..self.header_spec.header_len())
+            .ok_or(Error::BufferTooSmall)?;
+
+        if !self.lenient {
+            self.header_spec.validate(header)?;
+        }
+
+        self.header = Some(header.to_vec());
+        self.state = match key {
+            Some(key) => ReaderState::BodyInitial { key, offset: 0 },
+            None => ReaderState::BodyInitialNoKey,
+        };
+
+        Ok(header.len())
+    }
+
+    /// Set the key.
+    pub(crate) fn set_key(&mut self, key: [u8; KEY_LEN]) {
+        self.state = ReaderState::BodyInitial { key, offset: 0 };
+    }
+
+    /// Recover the key from the start of the body in the buffer.
+    pub(crate) fn recover_key<P>(&mut self, buffer: &[u8], plaintext: P) -> PartialKey
+    where
+        P: KnownPlaintext,
+    {
+        let key = plaintext.recover_key(buffer);
+
+        if let Some(key) = key.to_key() {
+            self.set_key(key);
+        }
+
+        key
+    }
+
+    /// Decrypt bytes that were just read from the encrypted start of the body.
+    ///
+    /// These must not be longer than the rest of the encrypted start.
+    pub(crate) fn decrypt(&mut self, buffer: &mut [u8]) {
+        if let ReaderState::BodyInitial { key, offset } = &mut self.state {
+            for (key_byte, out_byte) in key[*offset..].iter().copied().zip(buffer.iter_mut()) {
+                *out_byte ^= key_byte;
+                *offset += 1;
+            }
+
+            if *offset == KEY_LEN {
+                self.state = ReaderState::Body { key: *key };
+            }
+        }
+    }
+
+    /// Update the state after seeking to a position in the body.
+    pub(crate) fn seek(&mut self, key: [u8; KEY_LEN], position: u64) {
+        self.state = match usize::try_from(position) {
+            Ok(offset) if offset < KEY_LEN => ReaderState::BodyInitial { key, offset },
+            _ => ReaderState::Body { key },
+        };
+    }
+}
+
+/// Check that a key decrypts the start of the body in the buffer into the given file kind.
+pub(crate) fn verify_key(buffer: &[u8], key: [u8; KEY_LEN], kind: FileKind) -> Result<(), Error> {
+    let mut decrypted = [0; KEY_LEN];
+    let decrypted_len = std::cmp::min(buffer.len(), KEY_LEN);
+    for ((out_byte, body_byte), key_byte) in decrypted
+        .iter_mut()
+        .zip(buffer.iter().copied())
+        .zip(key.iter().copied())
+    {
+        *out_byte = body_byte ^ key_byte;
+    }
+
+    kind.verify(&decrypted[..decrypted_len])
+}
+
+/// A reader for an encrypted file
 pub struct Reader<R> {
     reader: R,
-    state: ReaderState,
+    core: ReaderCore,
 }
 
 impl<R> Reader<R> {
@@ -31,9 +180,41 @@ impl<R> Reader<R> {
     pub fn new(reader: R) -> Self {
         Self {
             reader,
-            state: ReaderState::Header,
+            core: ReaderCore::new(None),
         }
     }
+
+    /// Create a new reader with a known key.
+    ///
+    /// This skips key extraction,
+    /// which is needed for files that are not pngs, like audio.
+    pub fn with_key(reader: R, key: Key) -> Self {
+        Self {
+            reader,
+            core: ReaderCore::new(Some(key.into_bytes())),
+        }
+    }
+
+    /// Set the header spec.
+    ///
+    /// This must be called before the header is read, or it will have no effect.
+    pub fn set_header_spec(&mut self, header_spec: HeaderSpec) {
+        self.core.set_header_spec(header_spec);
+    }
+
+    /// Set whether the header should be validated.
+    ///
+    /// In lenient mode, only the header length is used.
+    /// The header that was found can be inspected with [`Reader::header`].
+    /// This must be called before the header is read, or it will have no effect.
+    pub fn set_lenient(&mut self, lenient: bool) {
+        self.core.set_lenient(lenient);
+    }
+
+    /// Get the header that was read, if it has been read.
+    pub fn header(&self) -> Option<&[u8]> {
+        self.core.header()
+    }
 }
 
 impl<R> Reader<R>
@@ -42,67 +223,100 @@ where
 {
     /// Read and validate the header.
     pub fn read_header(&mut self) -> Result<(), Error> {
-        match self.state {
-            ReaderState::Header => {}
+        match self.core.phase() {
+            ReaderPhase::Header => {}
             _ => return Ok(()),
         }
 
         let buffer = self.reader.fill_buf()?;
-        let header = buffer.get(..16).ok_or(Error::BufferTooSmall)?;
-
-        let magic: [u8; 9] = header[..9].try_into().unwrap();
-        let version: [u8; 3] = header[9..12].try_into().unwrap();
-        let padding: [u8; 4] = header[12..].try_into().unwrap();
-
-        if magic != MAGIC {
-            return Err(Error::InvalidMagic { magic });
-        }
+        let header_len = self.core.read_header(buffer)?;
+        self.reader.consume(header_len);
 
-        if version != VERSION {
-            return Err(Error::InvalidVersion { version });
-        }
+        Ok(())
+    }
 
-        if padding != HEADER_PADDING {
-            return Err(Error::InvalidHeaderPadding { padding });
+    /// Set the key manually.
+    ///
+    /// This prevents the Reader from automatically extracting the key.
+    /// If the key has already been determined by any means, this is a nop.
+    pub fn set_key(&mut self, key: [u8; KEY_LEN]) -> Result<(), Error> {
+        loop {
+            match self.core.phase() {
+                ReaderPhase::Header => {
+                    self.read_header()?;
+                }
+                ReaderPhase::NoKey => break,
+                ReaderPhase::BodyInitial { .. } | ReaderPhase::Body { .. } => {
+                    return Ok(());
+                }
+            }
         }
 
-        let header_len = header.len();
-        self.reader.consume(header_len);
-        self.state = ReaderState::BodyInitialNoKey;
+        self.core.set_key(key);
 
         Ok(())
     }
 
     /// Determine the encryption key.
+    ///
+    /// If the key has already been determined by any means, it is returned as-is.
+    /// Otherwise, this only works for encrypted pngs.
+    /// Use [`Reader::recover_key`] for other file types.
     pub fn extract_key(&mut self) -> Result<[u8; KEY_LEN], Error> {
+        self.recover_key(PngPlaintext)?
+            .to_key()
+            .ok_or(Error::BufferTooSmall)
+    }
+
+    /// Recover the encryption key using the known plaintext of a file format.
+    ///
+    /// If the key has already been determined by any means, it is returned as-is.
+    /// If the entire key was recovered, it will be used to decrypt the body.
+    /// Otherwise, the recovered bytes are returned,
+    /// and may be combined with the results from other files.
+    pub fn recover_key<P>(&mut self, plaintext: P) -> Result<PartialKey, Error>
+    where
+        P: KnownPlaintext,
+    {
         loop {
-            match self.state {
-                ReaderState::Header => {
+            match self.core.phase() {
+                ReaderPhase::Header => {
                     self.read_header()?;
                 }
-                ReaderState::BodyInitialNoKey => break,
-                ReaderState::BodyInitial { key, .. } | ReaderState::Body { key } => {
-                    return Ok(key);
+                ReaderPhase::NoKey => break,
+                ReaderPhase::BodyInitial { key, .. } | ReaderPhase::Body { key } => {
+                    return Ok(PartialKey::from_key(key));
                 }
             }
         }
 
         let buffer = self.reader.fill_buf()?;
-        let png_header = buffer.get(..KEY_LEN).ok_or(Error::BufferTooSmall)?;
-
-        let mut key = [0; KEY_LEN];
-        for ((expected_png_byte, actual_png_byte), key_byte) in PNG_HEADER
-            .iter()
-            .copied()
-            .zip(png_header.iter().copied())
-            .zip(key.iter_mut())
-        {
-            *key_byte = actual_png_byte ^ expected_png_byte;
-        }
+        Ok(self.core.recover_key(buffer, plaintext))
+    }
 
-        self.state = ReaderState::BodyInitial { key, offset: 0 };
+    /// Check that the key decrypts the start of the body into the given file kind.
+    ///
+    /// If no key has been determined yet, it will be recovered from the file kind.
+    /// This must be called before reading any of the body.
+    /// If part of the body has already been read, this is a nop.
+    pub fn verify_key(&mut self, kind: FileKind) -> Result<(), Error> {
+        let key = loop {
+            match self.core.phase() {
+                ReaderPhase::Header => {
+                    self.read_header()?;
+                }
+                ReaderPhase::NoKey => {
+                    if !self.recover_key(kind.known_plaintext())?.is_complete() {
+                        return Err(Error::MissingKey);
+                    }
+                }
+                ReaderPhase::BodyInitial { key, offset: 0 } => break key,
+                ReaderPhase::BodyInitial { .. } | ReaderPhase::Body { .. } => return Ok(()),
+            }
+        };
 
-        Ok(key)
+        let buffer = self.reader.fill_buf()?;
+        verify_key(buffer, key, kind)
     }
 }
 
@@ -112,34 +326,52 @@ where
 {
     fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
         loop {
-            match &mut self.state {
-                ReaderState::Header => {
+            match self.core.phase() {
+                ReaderPhase::Header => {
                     self.read_header().map_err(std::io::Error::other)?;
                 }
-                ReaderState::BodyInitialNoKey => {
+                ReaderPhase::NoKey => {
                     self.extract_key().map_err(std::io::Error::other)?;
                 }
-                ReaderState::BodyInitial { key, offset } => {
-                    let buffer_len = std::cmp::min(buffer.len(), KEY_LEN - *offset);
+                ReaderPhase::BodyInitial { offset, .. } => {
+                    let buffer_len = std::cmp::min(buffer.len(), KEY_LEN - offset);
                     let n = self.reader.read(&mut buffer[..buffer_len])?;
-
-                    for (key_byte, out_byte) in
-                        key[*offset..].iter().copied().zip(buffer[..n].iter_mut())
-                    {
-                        *out_byte ^= key_byte;
-                        *offset += 1;
-                    }
-
-                    if *offset == KEY_LEN {
-                        self.state = ReaderState::Body { key: *key };
-                    }
+                    self.core.decrypt(&mut buffer[..n]);
 
                     return Ok(n);
                 }
-                ReaderState::Body { .. } => {
+                ReaderPhase::Body { .. } => {
                     return self.reader.read(buffer);
                 }
             }
         }
     }
 }
+
+impl<R> Seek for Reader<R>
+where
+    R: BufRead + Seek,
+{
+    /// Seek within the decrypted body.
+    ///
+    /// Position 0 is the first byte after the header.
+    /// If the key has not been determined yet, it will be extracted first.
+    fn seek(&mut self, position: SeekFrom) -> std::io::Result<u64> {
+        let key = loop {
+            match self.core.phase() {
+                ReaderPhase::Header => {
+                    self.read_header().map_err(std::io::Error::other)?;
+                }
+                ReaderPhase::NoKey => {
+                    self.extract_key().map_err(std::io::Error::other)?;
+                }
+                ReaderPhase::BodyInitial { key, .. } | ReaderPhase::Body { key } => break key,
+            }
+        };
+
+        let position = seek_body(&mut self.reader, position, self.core.header_len())?;
+        self.core.seek(key, position);
+
+        Ok(position)
+    }
+}
@@ -1,12 +1,15 @@
 use crate::Error;
-use crate::HEADER_PADDING;
-use crate::MAGIC;
-use crate::VERSION;
+use crate::HeaderSpec;
+use crate::KEY_LEN;
+use crate::seek_body;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
 use std::io::Write;
 
 /// The reader state
-#[derive(Debug, PartialEq)]
-enum WriterState {
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum WriterState {
     /// Writes header next
     Header,
 
@@ -17,10 +20,86 @@ enum WriterState {
     Body,
 }
 
-impl WriterState {
-    /// Check if this is the header state.
-    pub fn is_header(&self) -> bool {
-        matches!(self, Self::Header)
+/// The io-independent part of a writer.
+///
+/// This is shared between the sync and async writers.
+#[derive(Debug)]
+pub(crate) struct WriterCore {
+    state: WriterState,
+    key: [u8; KEY_LEN],
+    header_spec: HeaderSpec,
+}
+
+impl WriterCore {
+    /// Make a new writer core.
+    pub(crate) fn new(key: [u8; KEY_LEN]) -> Self {
+        Self {
+            state: WriterState::Header,
+            key,
+            header_spec: HeaderSpec::MV,
+        }
+    }
+
+    pub(crate) fn set_header_spec(&mut self, header_spec: HeaderSpec) {
+        self.header_spec = header_spec;
+    }
+
+    pub(crate) fn header_spec(&self) -> &HeaderSpec {
+        &self.header_spec
+    }
+
+    pub(crate) fn state(&self) -> WriterState {
+        self.state
+    }
+
+    /// Mark the header as written.
+    pub(crate) fn finish_header(&mut self) {
+        if self.state == WriterState::Header {
+            self.state = WriterState::BodyInitial { offset: 0 };
+        }
+    }
+
+    /// Encrypt as much of the input as fits in the encrypted start of the body.
+    ///
+    /// Returns the number of bytes placed in the output.
+    /// Call [`WriterCore::advance`] once they are written.
+    pub(crate) fn encrypt(&self, input: &[u8], output: &mut [u8; KEY_LEN]) -> usize {
+        let offset = match self.state {
+            WriterState::BodyInitial { offset } => offset,
+            WriterState::Header | WriterState::Body => return 0,
+        };
+
+        let mut len = 0;
+        for ((input_byte, key_byte), out_byte) in input
+            .iter()
+            .copied()
+            .zip(self.key[offset..].iter().copied())
+            .zip(output.iter_mut())
+        {
+            *out_byte = input_byte ^ key_byte;
+            len += 1;
+        }
+
+        len
+    }
+
+    /// Mark bytes of the encrypted start of the body as written.
+    pub(crate) fn advance(&mut self, written: usize) {
+        if let WriterState::BodyInitial { offset } = &mut self.state {
+            *offset += written;
+
+            if *offset == KEY_LEN {
+                self.state = WriterState::Body;
+            }
+        }
+    }
+
+    /// Update the state after seeking to a position in the body.
+    pub(crate) fn seek(&mut self, position: u64) {
+        self.state = match usize::try_from(position) {
+            Ok(offset) if offset < KEY_LEN => WriterState::BodyInitial { offset },
+            _ => WriterState::Body,
+        };
     }
 }
 
@@ -28,8 +107,7 @@ impl WriterState {
 #[derive(Debug)]
 pub struct Writer<W> {
     writer: W,
-    state: WriterState,
-    key: [u8; 16],
+    core: WriterCore,
 }
 
 impl<W> Writer<W> {
@@ -37,10 +115,16 @@ impl<W> Writer<W> {
     pub fn new(writer: W, key: [u8; 16]) -> Self {
         Self {
             writer,
-            state: WriterState::Header,
-            key,
+            core: WriterCore::new(key),
         }
     }
+
+    /// Set the header spec.
+    ///
+    /// This must be called before the header is written, or it will have no effect.
+    pub fn set_header_spec(&mut self, header_spec: HeaderSpec) {
+        self.core.set_header_spec(header_spec);
+    }
 }
 
 impl<W> Writer<W>
@@ -49,14 +133,16 @@ where
 {
     /// Write the header.
     pub fn write_header(&mut self) -> Result<(), Error> {
-        if !self.state.is_header() {
+        if self.core.state() != WriterState::Header {
             return Ok(());
         }
 
-        self.writer.write_all(&MAGIC)?;
-        self.writer.write_all(&VERSION)?;
-        self.writer.write_all(&HEADER_PADDING)?;
-        self.state = WriterState::BodyInitial { offset: 0 };
+        let header_spec = self.core.header_spec();
+        self.writer.write_all(header_spec.magic())?;
+        self.writer.write_all(header_spec.version())?;
+        let padding_len = u64::try_from(header_spec.padding_len()).unwrap();
+        std::io::copy(&mut std::io::repeat(0).take(padding_len), &mut self.writer)?;
+        self.core.finish_header();
 
         Ok(())
     }
@@ -68,28 +154,17 @@ where
 {
     fn write(&mut self, buffer: &[u8]) -> std::io::Result<usize> {
         loop {
-            match &mut self.state {
+            match self.core.state() {
                 WriterState::Header => {
                     self.write_header().map_err(std::io::Error::other)?;
                 }
-                WriterState::BodyInitial { offset } => {
-                    let mut written = 0;
-                    for (input_byte, key_byte) in buffer
-                        .iter()
-                        .copied()
-                        .zip(self.key[*offset..].iter().copied())
-                    {
-                        let out_byte = input_byte ^ key_byte;
-                        self.writer.write_all(&[out_byte])?;
-                        written += 1;
-                    }
-                    *offset += written;
-
-                    if *offset == 16 {
-                        self.state = WriterState::Body;
-                    }
-
-                    return Ok(written);
+                WriterState::BodyInitial { .. } => {
+                    let mut encrypted = [0; KEY_LEN];
+                    let len = self.core.encrypt(buffer, &mut encrypted);
+                    self.writer.write_all(&encrypted[..len])?;
+                    self.core.advance(len);
+
+                    return Ok(len);
                 }
                 WriterState::Body => {
                     return self.writer.write(buffer);
@@ -102,3 +177,22 @@ where
         self.writer.flush()
     }
 }
+
+impl<W> Seek for Writer<W>
+where
+    W: Write + Seek,
+{
+    /// Seek within the body.
+    ///
+    /// Position 0 is the first byte after the header.
+    /// If the header has not been written yet, it will be written first.
+    fn seek(&mut self, position: SeekFrom) -> std::io::Result<u64> {
+        self.write_header().map_err(std::io::Error::other)?;
+
+        let header_len = self.core.header_spec().header_len();
+        let position = seek_body(&mut self.writer, position, header_len)?;
+        self.core.seek(position);
+
+        Ok(position)
+    }
+}
@@ -0,0 +1,133 @@
+use super::AudioFile;
+
+/// A timed screen flash in an animation
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct AnimationFlashTiming {
+    /// The frame this timing starts on
+    pub frame: u32,
+
+    /// The flash duration, in frames
+    pub duration: u32,
+
+    /// The flash color, as rgba
+    pub color: Vec<u8>,
+}
+
+/// A timed sound effect in an animation
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct AnimationSoundTiming {
+    /// The frame this timing starts on
+    pub frame: u32,
+
+    /// The sound effect
+    pub se: AudioFile,
+}
+
+/// The rotation of an animation, in degrees
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct AnimationRotation {
+    /// The rotation around the x axis
+    pub x: i32,
+
+    /// The rotation around the y axis
+    pub y: i32,
+
+    /// The rotation around the z axis
+    pub z: i32,
+}
+
+/// An animation.
+///
+/// Unlike MV, MZ animations are Effekseer effects.
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct Animation {
+    /// The animation id
+    pub id: u32,
+
+    /// ?
+    #[serde(rename = "displayType")]
+    pub display_type: u32,
+
+    /// The Effekseer effect name
+    #[serde(rename = "effectName")]
+    pub effect_name: String,
+
+    /// The screen flashes
+    #[serde(rename = "flashTimings")]
+    pub flash_timings: Vec<AnimationFlashTiming>,
+
+    /// The animation name
+    pub name: String,
+
+    /// The x offset, in pixels
+    #[serde(rename = "offsetX")]
+    pub offset_x: i32,
+
+    /// The y offset, in pixels
+    #[serde(rename = "offsetY")]
+    pub offset_y: i32,
+
+    /// The rotation
+    pub rotation: AnimationRotation,
+
+    /// The scale, as a percent
+    pub scale: u32,
+
+    /// The sound effects
+    #[serde(rename = "soundTimings")]
+    pub sound_timings: Vec<AnimationSoundTiming>,
+
+    /// The playback speed, as a percent
+    pub speed: u32,
+
+    /// ?
+    ///
+    /// This is always empty in editor-created animations.
+    pub timings: Vec<serde_json::Value>,
+
+    /// Whether the animation is aligned to the bottom of the target
+    ///
+    /// This is not present in older versions.
+    #[serde(rename = "alignBottom", skip_serializing_if = "Option::is_none")]
+    pub align_bottom: Option<bool>,
+
+    /// ?
+    ///
+    /// This is not present in older versions.
+    #[serde(rename = "quakeTimings", skip_serializing_if = "Option::is_none")]
+    pub quake_timings: Option<Vec<serde_json::Value>>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    // A hand-written sample in the format of a new RPGMaker MZ project.
+    // TODO: Replace with a sample from a real project.
+    const ANIMATIONS: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test-data/animations/Animations.json"
+    ));
+
+    #[test]
+    fn animations() {
+        let animations: Vec<Option<Animation>> =
+            serde_json::from_str(ANIMATIONS).expect("failed to parse");
+        assert!(
+            animations[1]
+                .as_ref()
+                .expect("missing animation")
+                .effect_name
+                == "CureOne1"
+        );
+
+        let animations_ser = serde_json::to_string(&animations).expect("failed to serialize");
+        let animations_de: Vec<Option<Animation>> =
+            serde_json::from_str(&animations_ser).expect("failed to parse");
+
+        assert!(animations == animations_de);
+    }
+}
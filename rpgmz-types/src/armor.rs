@@ -0,0 +1,73 @@
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct ArmorTrait {
+    /// ?
+    pub code: u32,
+
+    /// ?
+    #[serde(rename = "dataId")]
+    pub data_id: u32,
+
+    /// ?
+    pub value: f32,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct Armor {
+    /// The troop id
+    pub id: u32,
+
+    /// ?
+    #[serde(rename = "atypeId")]
+    pub a_type_id: u32,
+
+    /// The armor description
+    pub description: String,
+
+    /// ?
+    #[serde(rename = "etypeId")]
+    pub e_type_id: u32,
+
+    /// ?
+    pub traits: Vec<ArmorTrait>,
+
+    /// ?
+    #[serde(rename = "iconIndex")]
+    pub icon_index: u32,
+
+    /// The armor name
+    pub name: String,
+
+    /// ?
+    pub note: String,
+
+    /// ?
+    pub params: Vec<i32>,
+
+    /// The cost of the armor.
+    pub price: u32,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    // A hand-written sample in the format of a new RPGMaker MZ project.
+    // TODO: Replace with a sample from a real project.
+    const ARMORS: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test-data/armors/Armors.json"
+    ));
+
+    #[test]
+    fn armors() {
+        let armors: Vec<Option<Armor>> = serde_json::from_str(ARMORS).expect("failed to parse");
+        assert!(armors[1].as_ref().expect("missing armor").name == "Shield");
+
+        let armors_ser = serde_json::to_string(&armors).expect("failed to serialize");
+        let armors_de: Vec<Option<Armor>> =
+            serde_json::from_str(&armors_ser).expect("failed to parse");
+
+        assert!(armors == armors_de);
+    }
+}
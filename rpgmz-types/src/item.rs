@@ -0,0 +1,125 @@
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct ItemDamage {
+    /// ?
+    pub critical: bool,
+
+    /// ?
+    #[serde(rename = "elementId")]
+    pub element_id: i32,
+
+    /// ?
+    pub formula: String,
+
+    /// ?
+    #[serde(rename = "type")]
+    pub kind: u32,
+
+    /// ?
+    pub variance: u32,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct ItemEffect {
+    /// ?
+    pub code: u32,
+
+    #[serde(rename = "dataId")]
+    pub data_id: u32,
+
+    /// ?
+    pub value1: f64,
+
+    /// ?
+    pub value2: f64,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct Item {
+    /// The item id
+    pub id: u32,
+
+    /// ?
+    #[serde(rename = "animationId")]
+    pub animation_id: u32,
+
+    /// ?
+    pub consumable: bool,
+
+    /// ?
+    pub damage: ItemDamage,
+
+    /// The item description
+    pub description: String,
+
+    /// ?
+    ///
+    /// The typing for this is incorrect, I have not encountered a sample where this is populated.
+    pub effects: Vec<ItemEffect>,
+
+    /// ?
+    #[serde(rename = "hitType")]
+    pub hit_type: u32,
+
+    /// ?
+    #[serde(rename = "iconIndex")]
+    pub icon_index: u32,
+
+    /// ?
+    #[serde(rename = "itypeId")]
+    pub i_type_id: u32,
+
+    /// The item name
+    pub name: String,
+
+    /// ?
+    pub note: String,
+
+    /// ?
+    pub occasion: u32,
+
+    /// The item price
+    pub price: u32,
+
+    /// ?
+    pub repeats: u32,
+
+    /// ?
+    pub scope: u32,
+
+    /// ?
+    pub speed: u32,
+
+    /// ?
+    #[serde(rename = "successRate")]
+    pub success_rate: u32,
+
+    /// ?
+    #[serde(rename = "tpGain")]
+    pub tp_gain: u32,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    // A hand-written sample in the format of a new RPGMaker MZ project.
+    // TODO: Replace with a sample from a real project.
+    const ITEMS: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test-data/items/Items.json"
+    ));
+
+    #[test]
+    fn items() {
+        let items: Vec<Option<Item>> = serde_json::from_str(ITEMS).expect("failed to parse");
+        assert!(items[1].as_ref().expect("missing item").name == "Potion");
+
+        let items_ser = serde_json::to_string(&items).expect("failed to serialize");
+        let items_de: Vec<Option<Item>> =
+            serde_json::from_str(&items_ser).expect("failed to parse");
+
+        assert!(items == items_de);
+    }
+}
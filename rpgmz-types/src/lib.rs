@@ -1,31 +1,57 @@
+mod actor;
+mod animation;
+mod armor;
 mod audio_file;
+mod class;
 mod common_event;
 mod encounter;
+mod enemy;
 mod event;
 mod event_command;
 mod event_page;
 mod event_page_condition;
 mod image_file;
+mod item;
 mod map;
+mod map_info;
 mod move_command;
 mod move_route;
+mod plugin;
+mod skill;
+mod state;
+mod system;
+mod tileset;
 mod troop;
 mod troop_member;
 mod troop_page;
 mod troop_page_condition;
+mod weapon;
 
+pub use self::actor::Actor;
+pub use self::animation::Animation;
+pub use self::armor::Armor;
 pub use self::audio_file::AudioFile;
+pub use self::class::Class;
 pub use self::common_event::CommonEvent;
 pub use self::encounter::Encounter;
+pub use self::enemy::Enemy;
 pub use self::event::Event;
 pub use self::event_command::EventCommand;
 pub use self::event_page::EventPage;
 pub use self::event_page_condition::EventPageCondition;
 pub use self::image_file::ImageFile;
+pub use self::item::Item;
 pub use self::map::Map;
+pub use self::map_info::MapInfo;
 pub use self::move_command::MoveCommand;
 pub use self::move_route::MoveRoute;
+pub use self::plugin::Plugin;
+pub use self::skill::Skill;
+pub use self::state::State;
+pub use self::system::System;
+pub use self::tileset::Tileset;
 pub use self::troop::Troop;
 pub use self::troop_member::TroopMember;
 pub use self::troop_page::TroopPage;
 pub use self::troop_page_condition::TroopPageCondition;
+pub use self::weapon::Weapon;
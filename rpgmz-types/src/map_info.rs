@@ -0,0 +1,52 @@
+/// An entry in the map tree
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct MapInfo {
+    /// The map id
+    pub id: u32,
+
+    /// Whether the map is expanded in the editor's map tree
+    pub expanded: bool,
+
+    /// The map name
+    pub name: String,
+
+    /// The order of the map in the editor's map tree
+    pub order: u32,
+
+    /// The id of the parent map, or 0 for none
+    #[serde(rename = "parentId")]
+    pub parent_id: u32,
+
+    /// ?
+    #[serde(rename = "scrollX")]
+    pub scroll_x: f64,
+
+    /// ?
+    #[serde(rename = "scrollY")]
+    pub scroll_y: f64,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    // A hand-written sample in the format of a new RPGMaker MZ project.
+    // TODO: Replace with a sample from a real project.
+    const MAP_INFOS: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test-data/map-infos/MapInfos.json"
+    ));
+
+    #[test]
+    fn map_infos() {
+        let map_infos: Vec<Option<MapInfo>> =
+            serde_json::from_str(MAP_INFOS).expect("failed to parse");
+        assert!(map_infos[1].as_ref().expect("missing map info").name == "MAP001");
+
+        let map_infos_ser = serde_json::to_value(&map_infos).expect("failed to serialize");
+        let map_infos_value: serde_json::Value =
+            serde_json::from_str(MAP_INFOS).expect("failed to parse");
+
+        assert!(map_infos_ser == map_infos_value);
+    }
+}
@@ -0,0 +1,144 @@
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct SkillDamage {
+    /// ?
+    pub critical: bool,
+
+    /// ?
+    #[serde(rename = "elementId")]
+    pub element_id: i32,
+
+    /// ?
+    pub formula: String,
+
+    /// ?
+    #[serde(rename = "type")]
+    pub kind: u32,
+
+    /// ?
+    pub variance: u32,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct SkillEffect {
+    /// ?
+    pub code: u32,
+
+    /// ?
+    #[serde(rename = "dataId")]
+    pub data_id: u32,
+
+    /// ?
+    pub value1: f32,
+
+    /// ?
+    pub value2: u32,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Skill {
+    /// The skill id
+    pub id: u32,
+
+    /// The animation id
+    #[serde(rename = "animationId")]
+    pub animation_id: i32,
+
+    /// ?
+    pub damage: SkillDamage,
+
+    /// The description of the skill.
+    pub description: String,
+
+    /// ?
+    pub effects: Vec<SkillEffect>,
+
+    /// ?
+    #[serde(rename = "hitType")]
+    pub hit_type: u32,
+
+    /// ?
+    #[serde(rename = "iconIndex")]
+    pub icon_index: u32,
+
+    /// ?
+    pub message1: String,
+
+    /// ?
+    pub message2: String,
+
+    /// ?
+    #[serde(rename = "mpCost")]
+    pub mp_cost: u32,
+
+    /// The skill name
+    pub name: String,
+
+    /// ?
+    pub note: String,
+
+    /// ?
+    pub occasion: u32,
+
+    /// ?
+    pub repeats: u32,
+
+    /// ?
+    #[serde(rename = "requiredWtypeId1")]
+    pub required_w_type_id_1: u32,
+
+    /// ?
+    #[serde(rename = "requiredWtypeId2")]
+    pub required_w_type_id_2: u32,
+
+    /// ?
+    pub scope: u32,
+
+    /// ?
+    pub speed: i32,
+
+    /// ?
+    #[serde(rename = "stypeId")]
+    pub s_type_id: u32,
+
+    /// ?
+    #[serde(rename = "successRate")]
+    pub success_rate: u32,
+
+    /// ?
+    #[serde(rename = "tpCost")]
+    pub tp_cost: u32,
+
+    /// ?
+    #[serde(rename = "tpGain")]
+    pub tp_gain: u32,
+
+    /// ?
+    #[serde(rename = "messageType")]
+    pub message_type: u32,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    // A hand-written sample in the format of a new RPGMaker MZ project.
+    // TODO: Replace with a sample from a real project.
+    const SKILLS: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test-data/skills/Skills.json"
+    ));
+
+    #[test]
+    fn skills() {
+        let skills: Vec<Option<Skill>> = serde_json::from_str(SKILLS).expect("failed to parse");
+        assert!(skills[1].as_ref().expect("missing skill").message_type == 1);
+
+        let skills_ser = serde_json::to_string(&skills).expect("failed to serialize");
+        let skills_de: Vec<Option<Skill>> =
+            serde_json::from_str(&skills_ser).expect("failed to parse");
+
+        assert!(skills == skills_de);
+    }
+}
@@ -0,0 +1,125 @@
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct StateTrait {
+    /// ?
+    pub code: u32,
+
+    /// ?
+    #[serde(rename = "dataId")]
+    pub data_id: u32,
+
+    /// ?
+    pub value: f64,
+}
+
+/// A state
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct State {
+    /// The state id
+    pub id: u32,
+
+    /// ?
+    #[serde(rename = "autoRemovalTiming")]
+    pub auto_removal_timing: u32,
+
+    /// ?
+    #[serde(rename = "chanceByDamage")]
+    pub chance_by_damage: u32,
+
+    /// ?
+    #[serde(rename = "iconIndex")]
+    pub icon_index: u32,
+
+    /// ?
+    #[serde(rename = "maxTurns")]
+    pub max_turns: u32,
+
+    /// The message when an actor gets this state
+    pub message1: String,
+
+    /// The message when an enemy gets this state
+    pub message2: String,
+
+    /// The message when this state persists
+    pub message3: String,
+
+    /// The message when this state is removed
+    pub message4: String,
+
+    /// ?
+    #[serde(rename = "minTurns")]
+    pub min_turns: u32,
+
+    /// ?
+    pub motion: u32,
+
+    /// The state name
+    pub name: String,
+
+    /// ?
+    pub note: String,
+
+    /// ?
+    pub overlay: u32,
+
+    /// ?
+    pub priority: u32,
+
+    /// ?
+    #[serde(rename = "releaseByDamage")]
+    pub release_by_damage: bool,
+
+    /// ?
+    #[serde(rename = "removeAtBattleEnd")]
+    pub remove_at_battle_end: bool,
+
+    /// ?
+    #[serde(rename = "removeByDamage")]
+    pub remove_by_damage: bool,
+
+    /// ?
+    #[serde(rename = "removeByRestriction")]
+    pub remove_by_restriction: bool,
+
+    /// ?
+    #[serde(rename = "removeByWalking")]
+    pub remove_by_walking: bool,
+
+    /// ?
+    pub restriction: u32,
+
+    /// ?
+    #[serde(rename = "stepsToRemove")]
+    pub steps_to_remove: u32,
+
+    /// ?
+    pub traits: Vec<StateTrait>,
+
+    /// ?
+    #[serde(rename = "messageType")]
+    pub message_type: u32,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    // A hand-written sample in the format of a new RPGMaker MZ project.
+    // TODO: Replace with a sample from a real project.
+    const STATES: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test-data/states/States.json"
+    ));
+
+    #[test]
+    fn states() {
+        let states: Vec<Option<State>> = serde_json::from_str(STATES).expect("failed to parse");
+        assert!(states[1].as_ref().expect("missing state").message_type == 1);
+
+        let states_ser = serde_json::to_string(&states).expect("failed to serialize");
+        let states_de: Vec<Option<State>> =
+            serde_json::from_str(&states_ser).expect("failed to parse");
+
+        assert!(states == states_de);
+    }
+}
@@ -0,0 +1,433 @@
+use super::AudioFile;
+use std::collections::BTreeMap;
+
+/// Advanced settings
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct SystemAdvanced {
+    /// ?
+    #[serde(rename = "gameId")]
+    pub game_id: u32,
+
+    /// The screen width
+    #[serde(rename = "screenWidth")]
+    pub screen_width: u16,
+
+    /// The screen height
+    #[serde(rename = "screenHeight")]
+    pub screen_height: u16,
+
+    /// ?
+    #[serde(rename = "uiAreaWidth")]
+    pub ui_area_width: u16,
+
+    /// ?
+    #[serde(rename = "uiAreaHeight")]
+    pub ui_area_height: u16,
+
+    /// The font file name for numbers
+    #[serde(rename = "numberFontFilename")]
+    pub number_font_filename: String,
+
+    /// A css font list, used if the fonts fail to load
+    #[serde(rename = "fallbackFonts")]
+    pub fallback_fonts: String,
+
+    /// The font size
+    #[serde(rename = "fontSize")]
+    pub font_size: u16,
+
+    /// The main font file name
+    #[serde(rename = "mainFontFilename")]
+    pub main_font_filename: String,
+
+    /// ?
+    #[serde(rename = "windowOpacity")]
+    pub window_opacity: u8,
+
+    /// ?
+    ///
+    /// This is not present in older versions.
+    #[serde(rename = "screenScale", skip_serializing_if = "Option::is_none")]
+    pub screen_scale: Option<f64>,
+
+    /// ?
+    ///
+    /// This is not present in older versions.
+    #[serde(rename = "picturesUpperLimit", skip_serializing_if = "Option::is_none")]
+    pub pictures_upper_limit: Option<u32>,
+}
+
+/// A vehicle
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct SystemVehicle {
+    /// The music played while riding
+    pub bgm: AudioFile,
+
+    /// ?
+    #[serde(rename = "characterIndex")]
+    pub character_index: u32,
+
+    /// The character image name
+    #[serde(rename = "characterName")]
+    pub character_name: String,
+
+    /// The starting map id
+    #[serde(rename = "startMapId")]
+    pub start_map_id: u32,
+
+    /// The starting x position
+    #[serde(rename = "startX")]
+    pub start_x: u32,
+
+    /// The starting y position
+    #[serde(rename = "startY")]
+    pub start_y: u32,
+}
+
+/// An attack motion, by weapon type
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct SystemAttackMotion {
+    /// ?
+    #[serde(rename = "type")]
+    pub kind: u32,
+
+    /// ?
+    #[serde(rename = "weaponImageId")]
+    pub weapon_image_id: u32,
+}
+
+/// The game terms
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct SystemTerms {
+    /// Basic status terms, like "Level"
+    pub basic: Vec<String>,
+
+    /// Command names, like "Attack"
+    pub commands: Vec<Option<String>>,
+
+    /// Param names, like "Max HP"
+    pub params: Vec<String>,
+
+    /// Messages, by name
+    pub messages: BTreeMap<String, String>,
+}
+
+/// An actor used for battle tests
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct SystemTestBattler {
+    /// The actor id
+    #[serde(rename = "actorId")]
+    pub actor_id: u32,
+
+    /// The equipment ids, by equipment slot
+    pub equips: Vec<u32>,
+
+    /// The level
+    pub level: u32,
+}
+
+/// Editor settings
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct SystemEditor {
+    /// ?
+    #[serde(rename = "messageWidth1")]
+    pub message_width_1: u32,
+
+    /// ?
+    #[serde(rename = "messageWidth2")]
+    pub message_width_2: u32,
+
+    /// ?
+    #[serde(rename = "jsonFormatLevel")]
+    pub json_format_level: u32,
+}
+
+/// The title command window
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct SystemTitleCommandWindow {
+    /// ?
+    pub background: u32,
+
+    /// ?
+    #[serde(rename = "offsetX")]
+    pub offset_x: i32,
+
+    /// ?
+    #[serde(rename = "offsetY")]
+    pub offset_y: i32,
+}
+
+/// The System.json file
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct System {
+    /// Advanced settings
+    pub advanced: SystemAdvanced,
+
+    /// The airship
+    pub airship: SystemVehicle,
+
+    /// Armor type names, by id
+    #[serde(rename = "armorTypes")]
+    pub armor_types: Vec<String>,
+
+    /// Attack motions, by weapon type id
+    #[serde(rename = "attackMotions")]
+    pub attack_motions: Vec<SystemAttackMotion>,
+
+    /// The battle music
+    #[serde(rename = "battleBgm")]
+    pub battle_bgm: AudioFile,
+
+    /// ?
+    #[serde(rename = "battleSystem")]
+    pub battle_system: u32,
+
+    /// ?
+    #[serde(rename = "battleback1Name")]
+    pub battleback1_name: String,
+
+    /// ?
+    #[serde(rename = "battleback2Name")]
+    pub battleback2_name: String,
+
+    /// ?
+    #[serde(rename = "battlerHue")]
+    pub battler_hue: u32,
+
+    /// ?
+    #[serde(rename = "battlerName")]
+    pub battler_name: String,
+
+    /// The boat
+    pub boat: SystemVehicle,
+
+    /// The currency unit
+    #[serde(rename = "currencyUnit")]
+    pub currency_unit: String,
+
+    /// The music played on defeat
+    #[serde(rename = "defeatMe")]
+    pub defeat_me: AudioFile,
+
+    /// The id of the map open in the editor
+    #[serde(rename = "editMapId")]
+    pub edit_map_id: u32,
+
+    /// Editor settings
+    pub editor: SystemEditor,
+
+    /// Element names, by id
+    pub elements: Vec<String>,
+
+    /// Equipment type names, by id
+    #[serde(rename = "equipTypes")]
+    pub equip_types: Vec<String>,
+
+    /// ?
+    #[serde(rename = "faceSize")]
+    pub face_size: u32,
+
+    /// The game title
+    #[serde(rename = "gameTitle")]
+    pub game_title: String,
+
+    /// The music played on game over
+    #[serde(rename = "gameoverMe")]
+    pub gameover_me: AudioFile,
+
+    /// ?
+    #[serde(rename = "iconSize")]
+    pub icon_size: u32,
+
+    /// ?
+    #[serde(rename = "itemCategories")]
+    pub item_categories: Vec<bool>,
+
+    /// The locale
+    pub locale: String,
+
+    /// Skill type ids that count as magic
+    #[serde(rename = "magicSkills")]
+    pub magic_skills: Vec<u32>,
+
+    /// Which menu commands are enabled
+    #[serde(rename = "menuCommands")]
+    pub menu_commands: Vec<bool>,
+
+    /// ?
+    #[serde(rename = "optAutosave")]
+    pub opt_autosave: bool,
+
+    /// ?
+    #[serde(rename = "optDisplayTp")]
+    pub opt_display_tp: bool,
+
+    /// ?
+    #[serde(rename = "optDrawTitle")]
+    pub opt_draw_title: bool,
+
+    /// ?
+    #[serde(rename = "optExtraExp")]
+    pub opt_extra_exp: bool,
+
+    /// ?
+    #[serde(rename = "optFloorDeath")]
+    pub opt_floor_death: bool,
+
+    /// ?
+    #[serde(rename = "optFollowers")]
+    pub opt_followers: bool,
+
+    /// ?
+    #[serde(rename = "optKeyItemsNumber")]
+    pub opt_key_items_number: bool,
+
+    /// ?
+    #[serde(rename = "optMessageSkip")]
+    pub opt_message_skip: bool,
+
+    /// ?
+    #[serde(rename = "optSideView")]
+    pub opt_side_view: bool,
+
+    /// ?
+    #[serde(rename = "optSlipDeath")]
+    pub opt_slip_death: bool,
+
+    /// ?
+    #[serde(rename = "optSplashScreen")]
+    pub opt_splash_screen: bool,
+
+    /// ?
+    #[serde(rename = "optTransparent")]
+    pub opt_transparent: bool,
+
+    /// The actor ids of the starting party
+    #[serde(rename = "partyMembers")]
+    pub party_members: Vec<u32>,
+
+    /// The ship
+    pub ship: SystemVehicle,
+
+    /// Skill type names, by id
+    #[serde(rename = "skillTypes")]
+    pub skill_types: Vec<String>,
+
+    /// System sound effects
+    pub sounds: Vec<AudioFile>,
+
+    /// The starting map id
+    #[serde(rename = "startMapId")]
+    pub start_map_id: u32,
+
+    /// The starting x position
+    #[serde(rename = "startX")]
+    pub start_x: u32,
+
+    /// The starting y position
+    #[serde(rename = "startY")]
+    pub start_y: u32,
+
+    /// Switch names, by id
+    pub switches: Vec<String>,
+
+    /// The game terms
+    pub terms: SystemTerms,
+
+    /// The party used for battle tests
+    #[serde(rename = "testBattlers")]
+    pub test_battlers: Vec<SystemTestBattler>,
+
+    /// The troop id used for battle tests
+    #[serde(rename = "testTroopId")]
+    pub test_troop_id: u32,
+
+    /// ?
+    #[serde(rename = "tileSize")]
+    pub tile_size: u32,
+
+    /// ?
+    #[serde(rename = "title1Name")]
+    pub title1_name: String,
+
+    /// ?
+    #[serde(rename = "title2Name")]
+    pub title2_name: String,
+
+    /// The title music
+    #[serde(rename = "titleBgm")]
+    pub title_bgm: AudioFile,
+
+    /// ?
+    #[serde(rename = "titleCommandWindow")]
+    pub title_command_window: SystemTitleCommandWindow,
+
+    /// Variable names, by id
+    pub variables: Vec<String>,
+
+    /// ?
+    #[serde(rename = "versionId")]
+    pub version_id: u32,
+
+    /// The music played on victory
+    #[serde(rename = "victoryMe")]
+    pub victory_me: AudioFile,
+
+    /// Weapon type names, by id
+    #[serde(rename = "weaponTypes")]
+    pub weapon_types: Vec<String>,
+
+    /// The window tone, as rgb and gray
+    #[serde(rename = "windowTone")]
+    pub window_tone: Vec<i32>,
+
+    /// Whether images are encrypted
+    ///
+    /// This is only present in deployed games.
+    #[serde(rename = "hasEncryptedImages", skip_serializing_if = "Option::is_none")]
+    pub has_encrypted_images: Option<bool>,
+
+    /// Whether audio is encrypted
+    ///
+    /// This is only present in deployed games.
+    #[serde(rename = "hasEncryptedAudio", skip_serializing_if = "Option::is_none")]
+    pub has_encrypted_audio: Option<bool>,
+
+    /// The encryption key, as hex
+    ///
+    /// This is only present in deployed games.
+    #[serde(rename = "encryptionKey", skip_serializing_if = "Option::is_none")]
+    pub encryption_key: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    // A hand-written sample in the format of a deployed RPGMaker MZ project.
+    // TODO: Replace with a sample from a real project.
+    const SYSTEM: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test-data/system/System.json"
+    ));
+
+    #[test]
+    fn system() {
+        let system: System = serde_json::from_str(SYSTEM).expect("failed to parse");
+        assert!(system.tile_size == 48);
+        assert!(system.advanced.screen_width == 816);
+
+        let system_ser = serde_json::to_value(&system).expect("failed to serialize");
+        let system_value: serde_json::Value =
+            serde_json::from_str(SYSTEM).expect("failed to parse");
+
+        assert!(system_ser == system_value);
+    }
+}
@@ -0,0 +1,47 @@
+/// A tileset
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct Tileset {
+    /// The tileset id
+    pub id: u32,
+
+    /// The flags of each tile, like passability
+    pub flags: Vec<u16>,
+
+    /// ?
+    pub mode: u32,
+
+    /// The tileset name
+    pub name: String,
+
+    /// ?
+    pub note: String,
+
+    /// The image names of the A1 to A5, B, C, D, and E tilesets
+    #[serde(rename = "tilesetNames")]
+    pub tileset_names: Vec<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    // A hand-written sample in the format of a new RPGMaker MZ project.
+    // TODO: Replace with a sample from a real project.
+    const TILESETS: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test-data/tilesets/Tilesets.json"
+    ));
+
+    #[test]
+    fn tilesets() {
+        let tilesets: Vec<Option<Tileset>> =
+            serde_json::from_str(TILESETS).expect("failed to parse");
+        assert!(tilesets[1].as_ref().expect("missing tileset").flags.len() == 8192);
+
+        let tilesets_ser = serde_json::to_string(&tilesets).expect("failed to serialize");
+        let tilesets_de: Vec<Option<Tileset>> =
+            serde_json::from_str(&tilesets_ser).expect("failed to parse");
+
+        assert!(tilesets == tilesets_de);
+    }
+}
@@ -0,0 +1,77 @@
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct WeaponTrait {
+    /// ?
+    pub code: u32,
+
+    /// ?
+    #[serde(rename = "dataId")]
+    pub data_id: u32,
+
+    /// ?
+    pub value: f32,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Weapon {
+    /// The weapon id
+    pub id: u32,
+
+    /// ?
+    #[serde(rename = "animationId")]
+    pub animation_id: u32,
+
+    /// The weapon description
+    pub description: String,
+
+    /// ?
+    #[serde(rename = "etypeId")]
+    pub e_type_id: u32,
+
+    /// ?
+    pub traits: Vec<WeaponTrait>,
+
+    /// ?
+    #[serde(rename = "iconIndex")]
+    pub icon_index: u32,
+
+    /// The weapon name
+    pub name: String,
+
+    /// ?
+    pub note: String,
+
+    /// ?
+    pub params: Vec<u32>,
+
+    /// The weapon's price
+    pub price: u32,
+
+    /// ?
+    #[serde(rename = "wtypeId")]
+    pub w_type_id: u32,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    // A hand-written sample in the format of a new RPGMaker MZ project.
+    // TODO: Replace with a sample from a real project.
+    const WEAPONS: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test-data/weapons/Weapons.json"
+    ));
+
+    #[test]
+    fn weapons() {
+        let weapons: Vec<Option<Weapon>> = serde_json::from_str(WEAPONS).expect("failed to parse");
+        assert!(weapons[1].as_ref().expect("missing weapon").name == "Sword");
+
+        let weapons_ser = serde_json::to_string(&weapons).expect("failed to serialize");
+        let weapons_de: Vec<Option<Weapon>> =
+            serde_json::from_str(&weapons_ser).expect("failed to parse");
+
+        assert!(weapons == weapons_de);
+    }
+}